@@ -2,7 +2,7 @@
 
 use crate::rust::*;
 
-use super::Size;
+use super::{Forest, Size};
 
 /// Visit a node in breadth first search.
 #[derive(Debug, PartialEq, Eq)]
@@ -97,6 +97,23 @@ impl<Iter> BfsForest<Iter> {
             size: self.size,
         }
     }
+
+    /// Collects the visits into a `Forest`, the same as `Forest::from( self )`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trees::tr;
+    ///
+    /// let forest = -( tr(1)/tr(2) ) -tr(3);
+    /// let cloned = forest.clone().into_bfs().map( |data| data ).collect_forest();
+    /// assert_eq!( cloned, forest );
+    /// ```
+    pub fn collect_forest<T>( self ) -> Forest<T>
+        where Iter : Iterator<Item=Visit<T>>
+    {
+        Forest::from( self )
+    }
 }
 
 /// Bfs iterator of either tree or forest.
@@ -108,6 +125,32 @@ pub enum Bfs<Iter> {
 impl<T,Iter> Bfs<Iter>
     where Iter: Iterator<Item=Visit<T>>
 {
+    /// Takes a closure and creates another Bfs which calls that closure on
+    /// each `Visit::data`, keeping its `size`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trees::{Size, tr};
+    /// use trees::bfs::Visit;
+    ///
+    /// let tree = tr(1) /tr(2);
+    /// let bfs = tree.into_bfs().wrap().map( |x| x.to_string() );
+    /// assert_eq!( bfs.iter().collect::<Vec<_>>(), vec![
+    ///     Visit{ data: "1".to_owned(), size: Size{ degree: 1, descendants: 1 }},
+    ///     Visit{ data: "2".to_owned(), size: Size{ degree: 0, descendants: 0 }},
+    /// ]);
+    /// ```
+    pub fn map<B,F>( self, mut f: F ) -> Bfs<impl Iterator<Item=Visit<B>>>
+        where F: FnMut(T) -> B
+    {
+        let f = move |visit: Visit<T>| Visit{ data: f( visit.data ), size: visit.size };
+        match self {
+            Bfs::Tree(   tree   ) => Bfs::Tree(   BfsTree  { iter: tree.iter.map( f ),   size: tree.size   }),
+            Bfs::Forest( forest ) => Bfs::Forest( BfsForest{ iter: forest.iter.map( f ), size: forest.size }),
+        }
+    }
+
     /// Returns the iterator in breadth-first search.
     pub fn iter( self ) -> Iter {
         match self {
@@ -157,6 +200,27 @@ pub struct Splitted<Iter> {
     pub(crate) iters : VecDeque<Iter>,
 }
 
+impl<Iter> Splitted<Iter> {
+    /// Creates a breadth-first iterator from sibling groups, visited in order as the top level.
+    /// Their items are expanded by `Split`, and each item's children iterator is queued behind.
+    ///
+    /// To assemble a `BfsForest` manually, pair it with the `Size` of the resulting forest:
+    /// the total count of top-level items as `degree`, and of all nodes as `descendants`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use trees::{Forest, Size, tr};
+    /// use trees::bfs::{BfsForest, Splitted};
+    ///
+    /// let groups = VecDeque::from( vec![ ( -tr(1) ).into_iter(), ( -( tr(2)/tr(3) ) ).into_iter() ]);
+    /// let bfs = BfsForest{ iter: Splitted::from_iters( groups ), size: Size{ degree: 2, descendants: 3 }};
+    /// assert_eq!( Forest::from( bfs ).to_string(), "( 1 2( 3 ) )" );
+    /// ```
+    pub fn from_iters( iters: VecDeque<Iter> ) -> Self { Splitted{ iters }}
+}
+
 impl<Treelike,Item,Iter> From<Treelike> for Splitted<Iter>
     where Treelike : IntoIterator<Item=Item,IntoIter=Iter>
         ,     Iter : Iterator<Item=Item>
@@ -209,6 +273,16 @@ mod miri_tests {
     }
 
     mod bfs_forest {
+        #[test] fn from_splitted_iters() {
+            use crate::rust::*;
+            use crate::{Forest, Size, tr};
+            use crate::bfs::{BfsForest, Splitted};
+
+            let groups = VecDeque::from( vec![ ( -tr(1) ).into_iter(), ( -( tr(2)/tr(3) ) ).into_iter() ]);
+            let bfs = BfsForest{ iter: Splitted::from_iters( groups ), size: Size{ degree: 2, descendants: 3 }};
+            assert_eq!( Forest::from( bfs ).to_string(), "( 1 2( 3 ) )" );
+        }
+
         #[test] fn map() {
             use crate::Forest;
 
@@ -218,5 +292,27 @@ mod miri_tests {
             assert_eq!( Forest::from( forest.bfs().map( ToOwned::to_owned )),
                 Forest::<i32>::from_tuple(( 0, (1,2,3), (4,5,6), )));
         }
+
+        #[test] fn collect_forest() {
+            use crate::tr;
+
+            let forest = -( tr(1)/tr(2) ) -tr(3);
+            let cloned = forest.clone().into_bfs().map( |data| data ).collect_forest();
+            assert_eq!( cloned, forest );
+        }
+    }
+
+    mod bfs {
+        #[test] fn map() {
+            use crate::{Size, tr};
+            use crate::bfs::Visit;
+
+            let tree = tr(1) /tr(2);
+            let bfs = tree.into_bfs().wrap().map( |x| x.to_string() );
+            assert_eq!( bfs.iter().collect::<Vec<_>>(), vec![
+                Visit{ data: "1".to_owned(), size: Size{ degree: 1, descendants: 1 }},
+                Visit{ data: "2".to_owned(), size: Size{ degree: 0, descendants: 0 }},
+            ]);
+        }
     }
 }
@@ -104,6 +104,20 @@ impl<T> Node<T> {
         BfsTree::from( self.into_iter(), Size{ degree: 1, descendants: self.size.descendants })
     }
 
+    /// Clones the data of the node and its descendants in a breadth-first manner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// assert_eq!( tree.root().to_vec_bfs(), vec![ 0, 1, 3, 2 ]);
+    /// ```
+    pub fn to_vec_bfs( &self ) -> Vec<T> where T: Clone {
+        self.bfs().iter.map( |visit| visit.data.clone() ).collect()
+    }
+
     /// Provides a forward iterator with mutable references in a breadth-first manner.
     ///
     /// # Examples
@@ -437,6 +451,13 @@ mod miri_tests {
             root.bfs_mut().iter.zip( 1.. ).for_each( |(visit,nth)| *visit.data += 10 * nth );
             assert_eq!( tree, Tree::<i32>::from_tuple(( 10, (21,42,53), (34,65,76), )));
         }
+
+        #[test] fn to_vec_bfs() {
+            use crate::tr;
+
+            let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+            assert_eq!( tree.root().to_vec_bfs(), vec![ 0, 1, 3, 2 ]);
+        }
     }
 
     mod forest {
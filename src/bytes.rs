@@ -0,0 +1,189 @@
+//! Compact binary encoding of trees.
+//!
+//! Nodes are laid out in pre-order, each as its degree, the length of its data and the data bytes.
+//! Degrees and lengths are encoded as LEB128 variable-length integers.
+
+use crate::rust::*;
+
+use super::{Node, Tree, TreeError};
+
+/// Data which can be encoded into bytes.
+pub trait ToBytes {
+    fn to_bytes( &self ) -> Vec<u8>;
+}
+
+/// Data which can be decoded from bytes.
+pub trait FromBytes: Sized {
+    /// Returns `None` if the bytes are not a valid encoding.
+    fn from_bytes( bytes: &[u8] ) -> Option<Self>;
+}
+
+macro_rules! impl_bytes_for_int {
+    ($($int:ty)*) => {$(
+        impl ToBytes for $int {
+            fn to_bytes( &self ) -> Vec<u8> { self.to_le_bytes().to_vec() }
+        }
+
+        impl FromBytes for $int {
+            fn from_bytes( bytes: &[u8] ) -> Option<Self> {
+                let mut buf = [0_u8; mem::size_of::<$int>()];
+                if bytes.len() != buf.len() {
+                    return None;
+                }
+                buf.copy_from_slice( bytes );
+                Some( <$int>::from_le_bytes( buf ))
+            }
+        }
+    )*};
+}
+
+impl_bytes_for_int!( u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 );
+
+impl ToBytes for String {
+    fn to_bytes( &self ) -> Vec<u8> { self.as_bytes().to_vec() }
+}
+
+impl FromBytes for String {
+    fn from_bytes( bytes: &[u8] ) -> Option<Self> { String::from_utf8( bytes.to_vec() ).ok() }
+}
+
+impl ToBytes for Vec<u8> {
+    fn to_bytes( &self ) -> Vec<u8> { self.clone() }
+}
+
+impl FromBytes for Vec<u8> {
+    fn from_bytes( bytes: &[u8] ) -> Option<Self> { Some( bytes.to_vec() )}
+}
+
+impl<T> Tree<T> {
+    /// Encodes the tree into bytes, which can be decoded by `Tree::from_bytes()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, tr};
+    ///
+    /// let tree = tr(0_u32) /( tr(1)/tr(2) ) /tr(3);
+    /// let bytes = tree.to_bytes();
+    /// assert_eq!( Tree::<u32>::from_bytes( &bytes ), Ok( tree ));
+    /// ```
+    pub fn to_bytes( &self ) -> Vec<u8> where T: ToBytes {
+        fn encode<T:ToBytes>( node: &Node<T>, bytes: &mut Vec<u8> ) {
+            let data = node.data().to_bytes();
+            write_varint( node.degree(), bytes );
+            write_varint( data.len(), bytes );
+            bytes.extend_from_slice( &data );
+            for child in node.iter() {
+                encode( child, bytes );
+            }
+        }
+        let mut bytes = Vec::new();
+        encode( self.root(), &mut bytes );
+        bytes
+    }
+
+    /// Decodes the tree from bytes produced by `Tree::to_bytes()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, TreeError, tr};
+    ///
+    /// let bytes = ( tr(0_u32) /tr(1) ).to_bytes();
+    /// assert_eq!( Tree::<u32>::from_bytes( &bytes ).unwrap().to_string(), "0( 1 )" );
+    /// assert_eq!( Tree::<u32>::from_bytes( &bytes[..bytes.len()-1] ), Err( TreeError::UnexpectedEnd ));
+    /// assert_eq!( Tree::<u16>::from_bytes( &bytes ), Err( TreeError::InvalidData{ offset: 2 }));
+    /// ```
+    pub fn from_bytes( bytes: &[u8] ) -> Result<Tree<T>, TreeError> where T: FromBytes {
+        fn decode<T:FromBytes>( bytes: &[u8], offset: &mut usize ) -> Result<Tree<T>, TreeError> {
+            let degree = read_varint( bytes, offset )?;
+            let len = read_varint( bytes, offset )?;
+            if bytes.len() - *offset < len {
+                return Err( TreeError::UnexpectedEnd );
+            }
+            let data = T::from_bytes( &bytes[ *offset .. *offset+len ])
+                .ok_or( TreeError::InvalidData{ offset: *offset })?;
+            *offset += len;
+
+            let mut tree = Tree::new( data );
+            for _ in 0..degree {
+                tree.push_back( decode( bytes, offset )? );
+            }
+            Ok( tree )
+        }
+
+        let mut offset = 0;
+        let tree = decode( bytes, &mut offset )?;
+        if offset != bytes.len() {
+            return Err( TreeError::TrailingBytes{ offset });
+        }
+        Ok( tree )
+    }
+}
+
+fn write_varint( mut value: usize, bytes: &mut Vec<u8> ) {
+    while value >= 0x80 {
+        bytes.push( value as u8 | 0x80 );
+        value >>= 7;
+    }
+    bytes.push( value as u8 );
+}
+
+fn read_varint( bytes: &[u8], offset: &mut usize ) -> Result<usize, TreeError> {
+    let start = *offset;
+    let mut value = 0_usize;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get( *offset ).ok_or( TreeError::UnexpectedEnd )?;
+        *offset += 1;
+        let bits = ( byte & 0x7f ) as usize;
+        if shift >= usize::BITS || ( bits << shift ) >> shift != bits {
+            return Err( TreeError::InvalidLength{ offset: start });
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok( value );
+        }
+        shift += 7;
+    }
+}
+
+#[cfg( test )]
+mod tests {
+    use super::*;
+
+    #[test] fn varint_round_trip() {
+        for &value in &[ 0, 1, 0x7f, 0x80, 0x3fff, 0x4000, usize::MAX ] {
+            let mut bytes = Vec::new();
+            write_varint( value, &mut bytes );
+            let mut offset = 0;
+            assert_eq!( read_varint( &bytes, &mut offset ), Ok( value ));
+            assert_eq!( offset, bytes.len() );
+        }
+    }
+
+    #[test] fn varint_overflow() {
+        let bytes = [ 0xff_u8; 11 ];
+        assert_eq!( read_varint( &bytes, &mut 0 ), Err( TreeError::InvalidLength{ offset: 0 }));
+    }
+}
+
+#[cfg( miri )]
+mod miri_tests {
+    #[test] fn to_bytes() {
+        use crate::{Tree, tr};
+
+        let tree = tr(0_u32) /( tr(1)/tr(2) ) /tr(3);
+        let bytes = tree.to_bytes();
+        assert_eq!( Tree::<u32>::from_bytes( &bytes ), Ok( tree ));
+    }
+
+    #[test] fn from_bytes() {
+        use crate::{Tree, TreeError, tr};
+
+        let bytes = ( tr(0_u32) /tr(1) ).to_bytes();
+        assert_eq!( Tree::<u32>::from_bytes( &bytes ).unwrap().to_string(), "0( 1 )" );
+        assert_eq!( Tree::<u32>::from_bytes( &bytes[..bytes.len()-1] ), Err( TreeError::UnexpectedEnd ));
+        assert_eq!( Tree::<u16>::from_bytes( &bytes ), Err( TreeError::InvalidData{ offset: 2 }));
+    }
+}
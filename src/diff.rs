@@ -0,0 +1,93 @@
+//! Structural difference between two trees.
+
+use crate::rust::*;
+
+use super::{Node, Tree};
+
+/// One edit in the script produced by [`Tree::diff`].
+///
+/// Paths are child indices from the root. Paths of `Replace` and `Remove` refer to the
+/// old tree, while paths of `Insert` refer to the new tree.
+///
+/// [`Tree::diff`]: ../tree/struct.Tree.html#method.diff
+#[derive( Debug, PartialEq, Eq )]
+pub enum TreeEdit<'a, T:'a> {
+    /// The node at `path` exists in both trees but its data changed.
+    Replace{ path: Vec<usize>, old: &'a T, new: &'a T },
+    /// The subtree `node` at `path` exists in the new tree only.
+    Insert { path: Vec<usize>, node: &'a Node<T> },
+    /// The subtree `node` at `path` exists in the old tree only.
+    Remove { path: Vec<usize>, node: &'a Node<T> },
+}
+
+impl<T> Tree<T> {
+    /// Produces an edit script transforming `self` into `other`.
+    ///
+    /// This is a simple top-down positional diff: nodes are paired by their child index,
+    /// data of paired nodes are compared, and surplus children on either side are reported
+    /// as whole removed or inserted subtrees. Moved subtrees are not detected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{TreeEdit, tr};
+    ///
+    /// let old = tr(0) /tr(1) /tr(2);
+    /// let new = tr(0) /tr(9) /tr(2);
+    /// assert_eq!( old.diff( &new ), vec![ TreeEdit::Replace{ path: vec![0], old: &1, new: &9 }]);
+    ///
+    /// let new = tr(0) /tr(1);
+    /// assert_eq!( old.diff( &new ), vec![ TreeEdit::Remove{ path: vec![1], node: tr(2).root() }]);
+    ///
+    /// let new = tr(0) /tr(1) /tr(2) /( tr(3)/tr(4) );
+    /// assert_eq!( old.diff( &new ), vec![ TreeEdit::Insert{ path: vec![2], node: ( tr(3)/tr(4) ).root() }]);
+    /// ```
+    pub fn diff<'a>( &'a self, other: &'a Tree<T> ) -> Vec<TreeEdit<'a,T>>
+        where T: PartialEq
+    {
+        let mut edits = Vec::new();
+        diff_node( self.root(), other.root(), &mut Vec::new(), &mut edits );
+        edits
+    }
+}
+
+fn diff_node<'a, T:PartialEq>( old: &'a Node<T>, new: &'a Node<T>, path: &mut Vec<usize>, edits: &mut Vec<TreeEdit<'a,T>> ) {
+    if old.data() != new.data() {
+        edits.push( TreeEdit::Replace{ path: path.clone(), old: old.data(), new: new.data() });
+    }
+
+    let mut old_children = old.iter();
+    let mut new_children = new.iter();
+    let mut index = 0;
+    loop {
+        path.push( index );
+        match (old_children.next(), new_children.next()) {
+            (Some( old ), Some( new )) => diff_node( old, new, path, edits ),
+            (Some( old ), None       ) => edits.push( TreeEdit::Remove{ path: path.clone(), node: old }),
+            (None,        Some( new )) => edits.push( TreeEdit::Insert{ path: path.clone(), node: new }),
+            (None,        None       ) => {
+                path.pop();
+                break;
+            },
+        }
+        path.pop();
+        index += 1;
+    }
+}
+
+#[cfg( miri )]
+mod miri_tests {
+    #[test] fn diff() {
+        use crate::{TreeEdit, tr};
+
+        let old = tr(0) /tr(1) /tr(2);
+        let new = tr(0) /tr(9) /tr(2);
+        assert_eq!( old.diff( &new ), vec![ TreeEdit::Replace{ path: vec![0], old: &1, new: &9 }]);
+
+        let new = tr(0) /tr(1);
+        assert_eq!( old.diff( &new ), vec![ TreeEdit::Remove{ path: vec![1], node: tr(2).root() }]);
+
+        let new = tr(0) /tr(1) /tr(2) /( tr(3)/tr(4) );
+        assert_eq!( old.diff( &new ), vec![ TreeEdit::Insert{ path: vec![2], node: ( tr(3)/tr(4) ).root() }]);
+    }
+}
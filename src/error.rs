@@ -0,0 +1,54 @@
+//! Errors of tree construction and decoding.
+
+use crate::rust::*;
+
+/// Error returned when a tree cannot be built from its external representation.
+#[derive( Clone, Debug, PartialEq, Eq )]
+pub enum TreeError {
+    /// The edge at `edge` refers to a parent node which is defined by a later edge.
+    ForwardReference{ edge: usize, parent: usize },
+    /// The edge at `edge` refers to a parent node which does not exist.
+    InvalidParent{ edge: usize, parent: usize },
+    /// The input ends in the middle of a node.
+    UnexpectedEnd,
+    /// The length prefix at `offset` is malformed or too large.
+    InvalidLength{ offset: usize },
+    /// The data at `offset` cannot be decoded.
+    InvalidData{ offset: usize },
+    /// Extra input starts at `offset` after the tree is complete.
+    TrailingBytes{ offset: usize },
+    /// The character at `offset` is not allowed there.
+    UnexpectedChar{ offset: usize },
+    /// The tree to link is the node itself or one of its ancestors.
+    Cycle,
+    /// The tree to link is still attached to a parent.
+    Attached,
+}
+
+impl Display for TreeError {
+    fn fmt( &self, f: &mut Formatter ) -> fmt::Result {
+        match self {
+            TreeError::ForwardReference{ edge, parent } =>
+                write!( f, "edge {} refers to node {} before it is defined", edge, parent ),
+            TreeError::InvalidParent{ edge, parent } =>
+                write!( f, "edge {} refers to nonexistent node {}", edge, parent ),
+            TreeError::UnexpectedEnd =>
+                write!( f, "unexpected end of input" ),
+            TreeError::InvalidLength{ offset } =>
+                write!( f, "invalid length at offset {}", offset ),
+            TreeError::InvalidData{ offset } =>
+                write!( f, "invalid data at offset {}", offset ),
+            TreeError::TrailingBytes{ offset } =>
+                write!( f, "trailing bytes at offset {}", offset ),
+            TreeError::UnexpectedChar{ offset } =>
+                write!( f, "unexpected character at offset {}", offset ),
+            TreeError::Cycle =>
+                write!( f, "a node cannot be linked under itself" ),
+            TreeError::Attached =>
+                write!( f, "the tree to link is still attached to a parent" ),
+        }
+    }
+}
+
+#[cfg(not(feature="no_std"))]
+impl std::error::Error for TreeError {}
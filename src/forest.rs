@@ -53,6 +53,38 @@ impl<T> Forest<T> {
         Forest::from_node( unsafe{ node_vec.as_ref().non_null_node(0) })
     }
 
+    /// Constructs a forest of one tree per distinct key, in order of first appearance.
+    /// Each tree's root data is made by `key_data`, and the values of its key become its leaves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Forest;
+    ///
+    /// let forest = Forest::from_grouped( vec![ (1,"a"), (1,"b"), (2,"c") ],
+    ///     |key| if *key == 1 { "one" } else { "two" });
+    /// assert_eq!( forest.to_string(), "( one( a b ) two( c ) )" );
+    /// ```
+    #[cfg(not(feature="no_std"))]
+    pub fn from_grouped<K,I,F>( items: I, key_data: F ) -> Forest<T>
+        where K : Eq + Hash
+            , I : IntoIterator<Item=(K,T)>
+            , F : Fn(&K) -> T
+    {
+        let mut trees = Vec::<Tree<T>>::new();
+        let mut indices = HashMap::<K,usize>::new();
+        for (key, data) in items {
+            let index = *indices.entry( key ).or_insert_with_key( |key| {
+                trees.push( Tree::new( key_data( key )));
+                trees.len() - 1
+            });
+            trees[ index ].push_back( Tree::new( data ));
+        }
+        let mut forest = Forest::new();
+        trees.into_iter().for_each( |tree| forest.push_back( tree ));
+        forest
+    }
+
     pub(crate) fn from_node( root: NonNull<Node<T>> ) -> Forest<T> {
         Forest{ root, mark: PhantomData }
     }
@@ -141,6 +173,24 @@ impl<T> Forest<T> {
     /// ```
     pub fn iter_mut<'a, 's:'a>( &'s mut self ) -> IterMut<'a,T> { self.root_mut_().iter_mut() }
 
+    /// Calls a closure on the root node of each tree in the forest.
+    ///
+    /// A `&Tree` view of each root is not available: a `Tree` is an owning
+    /// handle pointing to its root node, and the forest stores only the linked
+    /// nodes, so there is no `Tree` value to borrow from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -tr(1)-tr(2)-tr(3);
+    /// let mut sum = 0;
+    /// forest.each_root( |root| sum += root.data() );
+    /// assert_eq!( sum, 6 );
+    /// ```
+    pub fn each_root<F:FnMut(&Node<T>)>( &self, f: F ) { self.iter().for_each( f ); }
+
     /// Returns the first child of the forest,
     /// or `None` if it is empty.
     pub fn front( &self ) -> Option<&Node<T>> {
@@ -179,6 +229,24 @@ impl<T> Forest<T> {
         self.root_mut_().push_front( tree );
     }
 
+    /// Add the trees at front of children list, preserving their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    /// let mut forest = -tr(3);
+    /// forest.push_front_many( vec![ tr(1), tr(2) ]);
+    /// assert_eq!( forest.to_string(), "( 1 2 3 )" );
+    /// ```
+    pub fn push_front_many<I:IntoIterator<Item=Tree<T>>>( &mut self, trees: I ) {
+        let mut forest = Forest::new();
+        for tree in trees {
+            forest.push_back( tree );
+        }
+        self.prepend( forest );
+    }
+
     /// Add the tree as the last child.
     ///
     /// # Examples
@@ -270,6 +338,257 @@ impl<T> Forest<T> {
     pub fn append( &mut self, forest: Forest<T> ) {
         self.root_mut_().append( forest );
     }
+
+    /// Splits the forest into forests of `n` trees each, in order.
+    /// The last forest may have fewer trees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -tr(1) -tr(2) -tr(3) -tr(4) -tr(5);
+    /// let chunks = forest.chunks(2).map( |chunk| chunk.to_string() ).collect::<Vec<_>>();
+    /// assert_eq!( chunks, vec![ "( 1 2 )", "( 3 4 )", "( 5 )" ]);
+    /// ```
+    pub fn chunks( self, n: usize ) -> impl Iterator<Item=Forest<T>> {
+        assert!( n != 0, "chunks: chunk size must be nonzero" );
+        let mut forest = self;
+        iter::from_fn( move || {
+            if forest.has_no_child() {
+                return None;
+            }
+            let mut chunk = Forest::new();
+            for _ in 0..n {
+                match forest.pop_front() {
+                    Some( tree ) => chunk.push_back( tree ),
+                    None => break,
+                }
+            }
+            Some( chunk )
+        })
+    }
+
+    /// Makes a forest of the same shape, whose data are mapped from this forest's data by `f`.
+    /// The new forest is stored contiguously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -tr(1) -( tr(2)/tr(3) );
+    /// let mapped = forest.map( |x| x+100 );
+    /// assert_eq!( mapped.to_string(), "( 101 102( 103 ) )" );
+    /// assert_eq!( mapped.node_count(), 3 );
+    /// ```
+    pub fn map<U,F:FnMut(&T)->U>( &self, f: F ) -> Forest<U> {
+        self.bfs().map( f ).collect_forest()
+    }
+
+    /// Pairs the trees of two forests in order, combining each pair into a tree of the new forest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the forests have different numbers of trees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, tr};
+    ///
+    /// let forest = ( -tr(1) -tr(2) ).zip_with( -tr(10) -tr(20), |a, b| Tree::new( a.root_data() + b.root_data() ));
+    /// assert_eq!( forest.to_string(), "( 11 22 )" );
+    /// ```
+    pub fn zip_with<U,V,F>( mut self, mut other: Forest<U>, mut f: F ) -> Forest<V>
+        where F: FnMut( Tree<T>, Tree<U> ) -> Tree<V>
+    {
+        assert_eq!( self.degree(), other.degree(), "zip_with: forests have different numbers of trees" );
+        let mut forest = Forest::new();
+        while let (Some( a ), Some( b )) = (self.pop_front(), other.pop_front()) {
+            forest.push_back( f( a, b ));
+        }
+        forest
+    }
+
+    /// Alternates the trees of two forests, starting with this one,
+    /// and appends the remaining trees of the longer forest at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = ( -tr(1)-tr(3)-tr(5) ).interleave( -tr(2)-tr(4) );
+    /// assert_eq!( forest.to_string(), "( 1 2 3 4 5 )" );
+    /// ```
+    pub fn interleave( mut self, mut other: Forest<T> ) -> Forest<T> {
+        let mut forest = Forest::new();
+        while !self.has_no_child() && !other.has_no_child() {
+            forest.push_back( self.pop_front().unwrap() );
+            forest.push_back( other.pop_front().unwrap() );
+        }
+        forest.append( self );
+        forest.append( other );
+        forest
+    }
+
+    /// Limits the forest to `max_roots` trees if it has more:
+    /// keeps the first `max_roots - 1` trees and moves the rest under a new last tree with `overflow_data`.
+    /// Does nothing if the forest has no more than `max_roots` trees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_roots` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut forest = -tr(1)-tr(2)-tr(3)-tr(4);
+    /// forest.summarize( 2, 9 );
+    /// assert_eq!( forest.to_string(), "( 1 9( 2 3 4 ) )" );
+    /// assert_eq!( forest.node_count(), 5 );
+    /// ```
+    pub fn summarize( &mut self, max_roots: usize, overflow_data: T ) {
+        assert!( max_roots > 0, "summarize: max_roots must be positive" );
+        if self.degree() > max_roots {
+            let mut overflow = Forest::new();
+            while self.degree() >= max_roots {
+                overflow.push_front( self.pop_back().unwrap() );
+            }
+            let mut tree = Tree::new( overflow_data );
+            tree.root_mut_().append( overflow );
+            self.push_back( tree );
+        }
+    }
+
+    /// Makes a tree whose root data is computed from the forest, with the forest's trees as its children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -tr(1) -( tr(2)/tr(3) ) -tr(4);
+    /// let tree = forest.adopt_with( |forest| forest.degree() );
+    /// assert_eq!( tree.to_string(), "3( 1 2( 3 ) 4 )" );
+    /// ```
+    pub fn adopt_with<F:FnOnce(&Forest<T>)->T>( self, f: F ) -> Tree<T> {
+        let mut tree = Tree::new( f( &self ));
+        tree.root_mut_().append( self );
+        tree
+    }
+
+    /// Returns the root node with the minimum data, scanning the roots only.
+    /// If several roots are equally minimum, the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -tr(3) -( tr(1)/tr(0) ) -tr(2);
+    /// assert_eq!( forest.min_root().unwrap().to_string(), "1( 0 )" );
+    /// ```
+    pub fn min_root( &self ) -> Option<&Node<T>> where T: Ord {
+        self.iter().min_by_key( |node| node.data() )
+    }
+
+    /// Returns the root node with the maximum data, scanning the roots only.
+    /// If several roots are equally maximum, the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -tr(3) -tr(1) -( tr(2)/tr(9) );
+    /// assert_eq!( forest.max_root().unwrap().data(), &3 );
+    /// ```
+    pub fn max_root( &self ) -> Option<&Node<T>> where T: Ord {
+        self.iter().max_by_key( |node| node.data() )
+    }
+
+    /// Returns `true` if `cmp` holds for each pair of consecutive root trees.
+    /// Empty or single-tree forests are always sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// assert!( ( -tr(1)-tr(2)-tr(3) ).is_sorted_by( |a,b| a.data() <= b.data() ));
+    /// assert!( !( -tr(3)-tr(1) ).is_sorted_by( |a,b| a.data() <= b.data() ));
+    /// ```
+    pub fn is_sorted_by<F:FnMut(&Node<T>,&Node<T>)->bool>( &self, mut cmp: F ) -> bool {
+        self.iter().zip( self.iter().skip(1) ).all( |(a,b)| cmp( a, b ))
+    }
+
+    /// Returns the maximum depth of nodes across all trees, the roots being at depth 0.
+    /// That is the longest `Node::longest_path_len()` of the roots, or 0 for an empty forest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Forest, tr};
+    ///
+    /// let forest = -( tr(1)/tr(2)/tr(3) ) -tr(4);
+    /// assert_eq!( forest.max_depth(), 1 );
+    /// assert_eq!( ( -tr(1) -tr(2) ).max_depth(), 0 );
+    /// assert_eq!( Forest::<i32>::new().max_depth(), 0 );
+    /// ```
+    pub fn max_depth( &self ) -> usize {
+        self.iter().map( Node::longest_path_len ).max().unwrap_or( 0 )
+    }
+
+    /// Collects the data of all nodes in the forest, in pre-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let forest = -( tr(1)/tr(2) ) -tr(3);
+    /// assert_eq!( forest.flatten(), vec![ &1, &2, &3 ]);
+    /// ```
+    pub fn flatten( &self ) -> Vec<&T> {
+        self.iter()
+            .flat_map( |tree| tree.subtree_preorder() )
+            .map( |node| node.data() )
+            .collect()
+    }
+
+    /// Removes the trees equal to some preceding tree, keeping the first occurrences.
+    /// Trees are bucketed by `Node::subtree_hash()` and compared for equality within a bucket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut forest = -( tr(1)/tr(2) ) -( tr(1)/tr(2) ) -tr(3);
+    /// forest.dedup_trees();
+    /// assert_eq!( forest.to_string(), "( 1( 2 ) 3 )" );
+    /// ```
+    #[cfg(not(feature="no_std"))]
+    pub fn dedup_trees( &mut self ) where T: Hash + Eq {
+        let mut kept = Vec::<Tree<T>>::with_capacity( self.degree() );
+        let mut buckets = HashMap::<u64,Vec<usize>>::new();
+        while let Some( tree ) = self.pop_front() {
+            let bucket = buckets.entry( tree.root().subtree_hash() ).or_default();
+            if !bucket.iter().any( |&index| kept[ index ] == tree ) {
+                bucket.push( kept.len() );
+                kept.push( tree );
+            }
+        }
+        kept.into_iter().for_each( |tree| self.push_back( tree ));
+    }
 }
 
 impl<T> Default for Forest<T> { fn default() -> Self { Forest::new() }}
@@ -357,6 +676,15 @@ mod miri_tests {
         assert_eq!( forest.to_string(), "( 10 20 )" );
     }
 
+    #[test] fn each_root() {
+        use crate::tr;
+
+        let forest = -tr(1)-tr(2)-tr(3);
+        let mut sum = 0;
+        forest.each_root( |root| sum += root.data() );
+        assert_eq!( sum, 6 );
+    }
+
     #[test] fn push_front() {
         use crate::{Forest, Tree};
 
@@ -367,6 +695,14 @@ mod miri_tests {
         assert_eq!( forest.to_string(), "( 2 1 )" );
     }
 
+    #[test] fn push_front_many() {
+        use crate::tr;
+
+        let mut forest = -tr(3);
+        forest.push_front_many( vec![ tr(1), tr(2) ]);
+        assert_eq!( forest.to_string(), "( 1 2 3 )" );
+    }
+
     #[test] fn push_back() {
         use crate::{Forest, Tree};
 
@@ -429,6 +765,107 @@ mod miri_tests {
         assert_eq!( forest.to_string(), "( 1 2 3 4 )" );
     }
 
+    #[test] fn chunks() {
+        use crate::tr;
+
+        let forest = -tr(1) -tr(2) -tr(3) -tr(4) -tr(5);
+        let chunks = forest.chunks(2).map( |chunk| chunk.to_string() ).collect::<Vec<_>>();
+        assert_eq!( chunks, vec![ "( 1 2 )", "( 3 4 )", "( 5 )" ]);
+    }
+
+    #[test] fn zip_with() {
+        use crate::{Tree, tr};
+
+        let forest = ( -tr(1) -tr(2) ).zip_with( -tr(10) -tr(20), |a, b| Tree::new( a.root_data() + b.root_data() ));
+        assert_eq!( forest.to_string(), "( 11 22 )" );
+    }
+
+    #[test] fn adopt_with() {
+        use crate::tr;
+
+        let forest = -tr(1) -( tr(2)/tr(3) ) -tr(4);
+        let tree = forest.adopt_with( |forest| forest.degree() );
+        assert_eq!( tree.to_string(), "3( 1 2( 3 ) 4 )" );
+    }
+
+    #[test] fn from_grouped() {
+        use crate::Forest;
+
+        let forest = Forest::from_grouped( vec![ (1,"a"), (1,"b"), (2,"c") ],
+            |key| if *key == 1 { "one" } else { "two" });
+        assert_eq!( forest.to_string(), "( one( a b ) two( c ) )" );
+    }
+
+    #[test] fn map() {
+        use crate::tr;
+
+        let forest = -tr(1) -( tr(2)/tr(3) );
+        let mapped = forest.map( |x| x+100 );
+        assert_eq!( mapped.to_string(), "( 101 102( 103 ) )" );
+        assert_eq!( mapped.node_count(), 3 );
+    }
+
+    #[test] fn summarize() {
+        use crate::tr;
+
+        let mut forest = -tr(1)-tr(2)-tr(3)-tr(4);
+        forest.summarize( 2, 9 );
+        assert_eq!( forest.to_string(), "( 1 9( 2 3 4 ) )" );
+        assert_eq!( forest.node_count(), 5 );
+    }
+
+    #[test] fn interleave() {
+        use crate::tr;
+
+        let forest = ( -tr(1)-tr(3)-tr(5) ).interleave( -tr(2)-tr(4) );
+        assert_eq!( forest.to_string(), "( 1 2 3 4 5 )" );
+    }
+
+    #[test] fn is_sorted_by() {
+        use crate::tr;
+
+        assert!( ( -tr(1)-tr(2)-tr(3) ).is_sorted_by( |a,b| a.data() <= b.data() ));
+        assert!( !( -tr(3)-tr(1) ).is_sorted_by( |a,b| a.data() <= b.data() ));
+    }
+
+    #[test] fn min_root() {
+        use crate::tr;
+
+        let forest = -tr(3) -( tr(1)/tr(0) ) -tr(2);
+        assert_eq!( forest.min_root().unwrap().to_string(), "1( 0 )" );
+    }
+
+    #[test] fn max_root() {
+        use crate::tr;
+
+        let forest = -tr(3) -tr(1) -( tr(2)/tr(9) );
+        assert_eq!( forest.max_root().unwrap().data(), &3 );
+    }
+
+    #[test] fn max_depth() {
+        use crate::{Forest, tr};
+
+        let forest = -( tr(1)/tr(2)/tr(3) ) -tr(4);
+        assert_eq!( forest.max_depth(), 1 );
+        assert_eq!( ( -tr(1) -tr(2) ).max_depth(), 0 );
+        assert_eq!( Forest::<i32>::new().max_depth(), 0 );
+    }
+
+    #[test] fn flatten() {
+        use crate::tr;
+
+        let forest = -( tr(1)/tr(2) ) -tr(3);
+        assert_eq!( forest.flatten(), vec![ &1, &2, &3 ]);
+    }
+
+    #[test] fn dedup_trees() {
+        use crate::tr;
+
+        let mut forest = -( tr(1)/tr(2) ) -( tr(1)/tr(2) ) -tr(3);
+        forest.dedup_trees();
+        assert_eq!( forest.to_string(), "( 1( 2 ) 3 )" );
+    }
+
     #[test] fn from_tuple() {
         use crate::{Forest, tr};
 
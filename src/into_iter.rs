@@ -31,6 +31,99 @@ impl<T> Drop for IntoIter<T> {
     }
 }
 
+/// Tree's owning iterator over data in pre-order.
+pub struct PreorderIntoIter<T> {
+    forest : Forest<T>,
+}
+
+impl<T> Iterator for PreorderIntoIter<T> {
+    type Item = T;
+
+    fn next( &mut self ) -> Option<T> {
+        let mut tree = self.forest.pop_front()?;
+        self.forest.prepend( tree.abandon() );
+        Some( tree.into_data() )
+    }
+
+    fn size_hint( &self ) -> (usize, Option<usize>) {
+        let node_count = self.forest.node_count();
+        (node_count, Some( node_count ))
+    }
+}
+
+impl<T> ExactSizeIterator for PreorderIntoIter<T> {}
+
+impl<T> FusedIterator for PreorderIntoIter<T> {}
+
+/// Node's draining iterator, removing its children one by one.
+pub struct DrainChildren<'a, T:'a> {
+    node : &'a mut Node<T>,
+}
+
+impl<'a, T:'a> Iterator for DrainChildren<'a,T> {
+    type Item = Tree<T>;
+
+    fn next( &mut self ) -> Option<Tree<T>> {
+        self.node.pop_front()
+    }
+
+    fn size_hint( &self ) -> (usize, Option<usize>) {
+        let degree = self.node.degree();
+        (degree, Some( degree ))
+    }
+}
+
+impl<'a, T:'a> ExactSizeIterator for DrainChildren<'a,T> {}
+
+impl<'a, T:'a> FusedIterator for DrainChildren<'a,T> {}
+
+impl<'a, T:'a> Drop for DrainChildren<'a,T> {
+    fn drop( &mut self ) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<T> Node<T> {
+    /// Removes the children one by one, yielding them as trees.
+    /// Children not yet yielded are removed and dropped with the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1) /tr(2) /tr(3);
+    /// let kept = tree.root_mut().drain_children()
+    ///     .filter( |child| child.root_data() % 2 == 0 )
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!( tree.to_string(), "0" );
+    /// kept.into_iter().for_each( |child| tree.push_back( child ));
+    /// assert_eq!( tree.to_string(), "0( 2 )" );
+    /// ```
+    pub fn drain_children( &mut self ) -> DrainChildren<'_,T> {
+        DrainChildren{ node: self }
+    }
+}
+
+impl<T> Tree<T> {
+    /// Consumes the tree, yielding its data in pre-order.
+    /// Nodes not yet visited are dropped with the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// assert_eq!( tree.into_preorder().collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+    /// ```
+    pub fn into_preorder( self ) -> PreorderIntoIter<T> {
+        let mut forest = Forest::<T>::new();
+        forest.push_back( self );
+        PreorderIntoIter{ forest }
+    }
+}
+
 impl<T> IntoIterator for Tree<T> {
     type Item = Tree<T>;
     type IntoIter = IntoIter<T>;
@@ -46,6 +139,17 @@ impl<T> IntoIterator for Forest<T> {
     type Item = Tree<T>;
     type IntoIter = IntoIter<T>;
 
+    /// Yields the child trees in order. Remaining trees are dropped with the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Forest;
+    ///
+    /// let forest = Forest::<i32>::from_tuple(( (1,2), (3,4) ));
+    /// let trees = forest.into_iter().map( |tree| tree.to_string() ).collect::<Vec<_>>();
+    /// assert_eq!( trees, vec![ "1( 2 )", "3( 4 )" ]);
+    /// ```
     fn into_iter( self ) -> IntoIter<T> { IntoIter{ forest: self, marker: PhantomData }}
 }
 
@@ -66,3 +170,66 @@ impl<'a, T:'a> IntoIterator for Pin<&'a mut Node<T>> {
         IterMut::once( Some( self.non_null() ))
     }
 }
+
+#[cfg( miri )]
+mod miri_tests {
+    #[test] fn into_preorder() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        assert_eq!( tree.into_preorder().collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+    }
+
+    #[test] fn into_preorder_partially_consumed() {
+        use crate::Tree;
+
+        let tree = Tree::<String>::from_tuple(( "0".to_owned(), ("1".to_owned(),"2".to_owned()), "3".to_owned() ));
+        let mut iter = tree.into_preorder();
+        assert_eq!( iter.len(), 4 );
+        assert_eq!( iter.next(), Some( "0".to_owned() ));
+        assert_eq!( iter.next(), Some( "1".to_owned() ));
+        assert_eq!( iter.len(), 2 );
+    }
+
+    #[test] fn drain_children() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1) /tr(2) /tr(3);
+        let kept = tree.root_mut().drain_children()
+            .filter( |child| child.root_data() % 2 == 0 )
+            .collect::<Vec<_>>();
+        assert_eq!( tree.to_string(), "0" );
+        kept.into_iter().for_each( |child| tree.push_back( child ));
+        assert_eq!( tree.to_string(), "0( 2 )" );
+    }
+
+    #[test] fn drain_children_partially_consumed() {
+        use crate::Tree;
+
+        let mut tree = Tree::<String>::from_tuple(( "0".to_owned(), ("1".to_owned(),"2".to_owned()), "3".to_owned() ));
+        {
+            let mut root = tree.root_mut();
+            let mut drain = root.drain_children();
+            assert_eq!( drain.len(), 2 );
+            assert_eq!( drain.next().unwrap().to_string(), "1( 2 )" );
+        }
+        assert_eq!( tree.to_string(), "0" );
+        assert_eq!( tree.node_count(), 1 );
+    }
+
+    #[test] fn forest_into_iter() {
+        use crate::Forest;
+
+        let forest = Forest::<i32>::from_tuple(( (1,2), (3,4) ));
+        let trees = forest.into_iter().map( |tree| tree.to_string() ).collect::<Vec<_>>();
+        assert_eq!( trees, vec![ "1( 2 )", "3( 4 )" ]);
+    }
+
+    #[test] fn forest_into_iter_partially_consumed() {
+        use crate::Forest;
+
+        let forest = Forest::<String>::from_tuple(( ("1".to_owned(),"2".to_owned()), ("3".to_owned(),"4".to_owned()) ));
+        let mut iter = forest.into_iter();
+        assert_eq!( iter.next().unwrap().to_string(), "1( 2 )" );
+    }
+}
@@ -86,16 +86,20 @@ pub mod rust {
     #[cfg(not(feature="no_std"))] pub use std::borrow::{Borrow, ToOwned};
     #[cfg(not(feature="no_std"))] pub use std::boxed::Box;
     #[cfg(not(feature="no_std"))] pub use std::cell::{Cell, Ref, RefMut, RefCell};
+    #[cfg(not(feature="no_std"))] pub use std::collections::BTreeMap;
     #[cfg(not(feature="no_std"))] pub use std::collections::VecDeque;
+    #[cfg(not(feature="no_std"))] pub use std::collections::HashMap;
+    #[cfg(not(feature="no_std"))] pub use std::collections::hash_map::DefaultHasher;
     #[cfg(not(feature="no_std"))] pub use std::cmp::Ordering::{self, *};
     #[cfg(not(feature="no_std"))] pub use std::fmt::{self, Debug, Display, Formatter};
     #[cfg(not(feature="no_std"))] pub use std::hash::{Hasher, Hash};
-    #[cfg(not(feature="no_std"))] pub use std::iter::{Iterator, FromIterator, IntoIterator, FusedIterator};
+    #[cfg(not(feature="no_std"))] pub use std::iter::{self, Iterator, FromIterator, IntoIterator, FusedIterator};
     #[cfg(not(feature="no_std"))] pub use std::marker::{PhantomData, Unpin};
     #[cfg(not(feature="no_std"))] pub use std::mem::{self, forget, transmute, MaybeUninit};
     #[cfg(not(feature="no_std"))] pub use std::ops::{Add, AddAssign, Deref, DerefMut, Div, Neg, Sub, SubAssign};
     #[cfg(not(feature="no_std"))] pub use std::pin::Pin;
     #[cfg(not(feature="no_std"))] pub use std::ptr::{self, NonNull, null, null_mut};
+    #[cfg(not(feature="no_std"))] pub use std::str::FromStr;
     #[cfg(not(feature="no_std"))] pub use std::rc::{Rc, Weak};
     #[cfg(not(feature="no_std"))] pub use std::vec::Vec;
 
@@ -106,23 +110,23 @@ pub mod rust {
     #[cfg(feature="no_std")] pub use self::alloc::string::String;
     #[cfg(feature="no_std")]
                 #[cfg(test)] pub use self::alloc::string::ToString;
+    #[cfg(feature="no_std")] pub use self::alloc::collections::BTreeMap;
     #[cfg(feature="no_std")] pub use self::alloc::collections::VecDeque;
-    #[cfg(feature="no_std")]
-                #[cfg(test)] pub use self::alloc::format;
+    #[cfg(feature="no_std")] pub use self::alloc::format;
     #[cfg(feature="no_std")] pub use self::alloc::rc::{Rc, Weak};
-    #[cfg(feature="no_std")]
-                #[cfg(test)] pub use self::alloc::vec;
+    #[cfg(feature="no_std")] pub use self::alloc::vec;
     #[cfg(feature="no_std")] pub use self::alloc::vec::Vec;
     #[cfg(feature="no_std")] pub use core::cell::{Cell, Ref, RefMut, RefCell};
     #[cfg(feature="no_std")] pub use core::cmp::Ordering::{self, *};
     #[cfg(feature="no_std")] pub use core::fmt::{self, Debug, Display, Formatter};
     #[cfg(feature="no_std")] pub use core::hash::{Hasher, Hash};
-    #[cfg(feature="no_std")] pub use core::iter::{Iterator, FromIterator, IntoIterator, FusedIterator};
+    #[cfg(feature="no_std")] pub use core::iter::{self, Iterator, FromIterator, IntoIterator, FusedIterator};
     #[cfg(feature="no_std")] pub use core::marker::{PhantomData, Unpin};
     #[cfg(feature="no_std")] pub use core::mem::{self, forget, transmute, MaybeUninit};
     #[cfg(feature="no_std")] pub use core::ops::{Add, AddAssign, Deref, DerefMut, Div, Neg, Sub, SubAssign};
     #[cfg(feature="no_std")] pub use core::pin::Pin;
     #[cfg(feature="no_std")] pub use core::ptr::{self, NonNull, null, null_mut};
+    #[cfg(feature="no_std")] pub use core::str::FromStr;
 }
 
 #[macro_use]
@@ -154,7 +158,7 @@ pub use iter::{Iter, IterMut};
 pub(crate) use iter::CountedRawIter;
 
 pub mod into_iter;
-pub use into_iter::IntoIter;
+pub use into_iter::{DrainChildren, IntoIter, PreorderIntoIter};
 
 pub mod heap;
 
@@ -170,4 +174,16 @@ pub use iter_rc::IterRc;
 pub mod rc;
 pub use rc::{RcNode, WeakNode};
 
+pub mod diff;
+pub use diff::TreeEdit;
+
+pub mod error;
+pub use error::TreeError;
+
+pub mod bytes;
+pub use bytes::{FromBytes, ToBytes};
+
+pub mod mem_size;
+pub use mem_size::TreeMemSize;
+
 pub(crate) mod bfs_impls;
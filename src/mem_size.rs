@@ -0,0 +1,78 @@
+//! Approximate memory accounting of trees.
+
+use crate::rust::*;
+
+use super::Node;
+
+/// Data which may own heap memory besides its inline size.
+pub trait TreeMemSize {
+    /// Returns the number of heap bytes owned by the value, excluding `size_of::<Self>()`.
+    /// Defaults to 0 for values owning no heap memory.
+    fn heap_size( &self ) -> usize { 0 }
+}
+
+macro_rules! impl_tree_mem_size_for_plain {
+    ($($ty:ty)*) => {$(
+        impl TreeMemSize for $ty {}
+    )*};
+}
+
+impl_tree_mem_size_for_plain!( () bool char u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 );
+
+impl<T:?Sized> TreeMemSize for &T {}
+
+impl TreeMemSize for String {
+    fn heap_size( &self ) -> usize { self.capacity() }
+}
+
+impl<T:TreeMemSize> TreeMemSize for Vec<T> {
+    fn heap_size( &self ) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map( TreeMemSize::heap_size ).sum::<usize>()
+    }
+}
+
+impl<T:TreeMemSize> TreeMemSize for Box<T> {
+    fn heap_size( &self ) -> usize { mem::size_of::<T>() + (**self).heap_size() }
+}
+
+impl<T:TreeMemSize> TreeMemSize for Option<T> {
+    fn heap_size( &self ) -> usize { self.as_ref().map_or( 0, TreeMemSize::heap_size )}
+}
+
+impl<T> Node<T> {
+    /// Estimates the heap bytes occupied by the subtree: the size of its nodes,
+    /// plus the heap memory owned by their data.
+    /// Allocator overhead and the bookkeeping of shared node buffers are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::mem::size_of;
+    /// use trees::{Node, Tree, tr};
+    ///
+    /// let tree = Tree::<u32>::from_tuple(( 0, (1,2,3), (4,5,6) ));
+    /// assert_eq!( tree.root().approx_heap_size(), 7 * size_of::<Node<u32>>() );
+    ///
+    /// let tree = tr( String::with_capacity(8) ) /tr( String::with_capacity(4) );
+    /// assert_eq!( tree.root().approx_heap_size(), 2 * size_of::<Node<String>>() + 12 );
+    /// ```
+    pub fn approx_heap_size( &self ) -> usize where T: TreeMemSize {
+        self.subtree_preorder()
+            .map( |node| mem::size_of::<Node<T>>() + node.data().heap_size() )
+            .sum()
+    }
+}
+
+#[cfg( miri )]
+mod miri_tests {
+    #[test] fn approx_heap_size() {
+        use crate::rust::*;
+        use crate::{Node, Tree, tr};
+
+        let tree = Tree::<u32>::from_tuple(( 0, (1,2,3), (4,5,6) ));
+        assert_eq!( tree.root().approx_heap_size(), 7 * mem::size_of::<Node<u32>>() );
+
+        let tree = tr( String::with_capacity(8) ) /tr( String::with_capacity(4) );
+        assert_eq!( tree.root().approx_heap_size(), 2 * mem::size_of::<Node<String>>() + 12 );
+    }
+}
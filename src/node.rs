@@ -2,11 +2,11 @@
 //!
 //! Can be converted to `RcNode`, which has shared ownership.
 
-use crate::Size;
+use crate::{Size, TupleTree};
 
 use crate::rust::*;
 
-use super::{Forest, Iter, IterMut, NodeVec, Tree};
+use super::{Forest, Iter, IterMut, NodeVec, Tree, TreeError};
 
 /// Data associated with `Node`.
 #[derive( Debug, PartialEq, Eq, PartialOrd, Ord, Hash )]
@@ -144,6 +144,17 @@ impl<T> Node<T> {
     /// ```
     pub fn degree( &self ) -> usize { self.size.degree }
 
+    /// Returns the number of child nodes in `Node`, the same as `degree()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    /// let tree = tr(0) /tr(1) /tr(2);
+    /// assert_eq!( tree.root().child_count(), 2 );
+    /// ```
+    pub fn child_count( &self ) -> usize { self.degree() }
+
     /// Returns the number of all child nodes in `Node`, including itself.
     ///
     /// # Examples
@@ -189,6 +200,100 @@ impl<T> Node<T> {
         None
     }
 
+    /// Returns `true` if `self` and `other` are the same node, by address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /tr(1) /tr(1);
+    /// let first = tree.front().unwrap();
+    /// assert!( first.is_same( first ));
+    /// assert!( !first.is_same( tree.back().unwrap() ));
+    /// ```
+    pub fn is_same( &self, other: &Node<T> ) -> bool { ptr::eq( self, other )}
+
+    /// Returns `true` if `self` is a proper ancestor of `other`.
+    /// Walks up from `other`, taking O(depth of `other`) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let one = tree.front().unwrap();
+    /// let two = one.front().unwrap();
+    /// let three = tree.back().unwrap();
+    /// assert!( tree.root().is_ancestor_of( two ));
+    /// assert!( one.is_ancestor_of( two ));
+    /// assert!( !one.is_ancestor_of( three ));
+    /// assert!( !one.is_ancestor_of( one ));
+    /// ```
+    pub fn is_ancestor_of( &self, other: &Node<T> ) -> bool {
+        let mut node = other;
+        while let Some( parent ) = node.parent() {
+            if ptr::eq( parent, self ) {
+                return true;
+            }
+            node = parent;
+        }
+        false
+    }
+
+    /// Returns the ancestor `k` levels up, following `parent()` `k` times,
+    /// or None if it runs past the root. Returns itself if `k == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) );
+    /// let two = tree.front().unwrap().front().unwrap();
+    /// assert!( two.nth_ancestor( 0 ).unwrap().is_same( two ));
+    /// assert!( two.nth_ancestor( 2 ).unwrap().is_same( tree.root() ));
+    /// assert!( two.nth_ancestor( 3 ).is_none() );
+    /// ```
+    pub fn nth_ancestor( &self, k: usize ) -> Option<&Node<T>> {
+        let mut node = self;
+        for _ in 0..k {
+            node = node.parent()?;
+        }
+        Some( node )
+    }
+
+    /// Returns the node following this one in pre-order of the whole tree,
+    /// or None if this is the last one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let mut node = Some( tree.root() );
+    /// let mut visited = Vec::new();
+    /// while let Some( curr ) = node {
+    ///     visited.push( *curr.data() );
+    ///     node = curr.next_in_preorder();
+    /// }
+    /// assert_eq!( visited, vec![ 0, 1, 2, 3 ]);
+    /// ```
+    pub fn next_in_preorder( &self ) -> Option<&Node<T>> {
+        if let Some( head ) = self.head {
+            return Some( unsafe{ &*head.as_ptr() });
+        }
+        let mut node = self;
+        loop {
+            if let Some( next ) = node.next {
+                return Some( unsafe{ &*next.as_ptr() });
+            }
+            node = unsafe{ &*node.up?.as_ptr() };
+        }
+    }
+
     /// Inserts sib tree before `self`.
     /// The newly inserted node will not be iterated over by the currently running iterator.
     ///
@@ -317,6 +422,26 @@ impl<T> Node<T> {
         }
     }
 
+    /// Provides a forward iterator over the sibling `Node`s, including itself, from the first one.
+    /// A root node of a tree is the only one in its sibling group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /tr(1) /tr(2) /tr(3);
+    /// let two = tree.iter().nth(1).unwrap();
+    /// assert_eq!( two.siblings().map( |node| *node.data() ).collect::<Vec<_>>(), vec![ 1, 2, 3 ]);
+    /// assert_eq!( tree.root().siblings().count(), 1 );
+    /// ```
+    pub fn siblings( &self ) -> Iter<'_,T> {
+        match self.up {
+            Some( up ) => unsafe{ &*up.as_ptr() }.iter(),
+            None => Iter::once( Some( self.non_null() )),
+        }
+    }
+
     /// Provides a forward iterator over child `Node`s with mutable references.
     ///
     /// # Examples
@@ -337,6 +462,22 @@ impl<T> Node<T> {
         }
     }
 
+    /// Calls a closure on each child `Node` with its index among siblings.
+    /// Children are passed pinned, the same as `iter_mut()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(9) /tr(9) /tr(9);
+    /// tree.root_mut().for_each_child_mut( |index, mut child| *child.data_mut() = index );
+    /// assert_eq!( tree.to_string(), "0( 0 1 2 )" );
+    /// ```
+    pub fn for_each_child_mut<F:FnMut(usize,Pin<&mut Node<T>>)>( &mut self, mut f: F ) {
+        self.iter_mut().enumerate().for_each( |(index, child)| f( index, child ));
+    }
+
     /// Returns the first child of this node,
     /// or None if it has no child.
     pub fn front( &self ) -> Option<&Node<T>> {
@@ -361,6 +502,85 @@ impl<T> Node<T> {
         self.tail.map( |tail| unsafe{ Pin::new_unchecked( &mut *tail.as_ptr() )})
     }
 
+    /// Returns the descendant reached by following the child indices in `path`,
+    /// or None if any index is out of range. An empty path returns the node itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// assert_eq!( tree.root().at_path( &[0,1] ).unwrap().data(), &3 );
+    /// assert_eq!( tree.root().at_path( &[] ).unwrap().data(), &0 );
+    /// assert!( tree.root().at_path( &[1,0] ).is_none() );
+    /// ```
+    pub fn at_path( &self, path: &[usize] ) -> Option<&Node<T>> {
+        let mut node = self;
+        for &index in path {
+            node = node.iter().nth( index )?;
+        }
+        Some( node )
+    }
+
+    /// Returns a mutable pointer to the descendant reached by following the child indices in `path`,
+    /// or None if any index is out of range. An empty path returns the node itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// *tree.root_mut().at_path_mut( &[0,1] ).unwrap().data_mut() = 9;
+    /// assert_eq!( tree.to_string(), "0( 1( 2 9 ) 4 )" );
+    /// assert!( tree.root_mut().at_path_mut( &[2] ).is_none() );
+    /// ```
+    pub fn at_path_mut( &mut self, path: &[usize] ) -> Option<Pin<&mut Node<T>>> {
+        let node = self.at_path( path )?.non_null();
+        Some( unsafe{ Pin::new_unchecked( &mut *node.as_ptr() )})
+    }
+
+    /// Returns the leftmost leaf of this node's subtree,
+    /// or itself if it has no child.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /( tr(3)/tr(4) );
+    /// assert_eq!( tree.root().first_leaf().data(), &2 );
+    /// assert_eq!( tr(5).root().first_leaf().data(), &5 );
+    /// ```
+    pub fn first_leaf( &self ) -> &Node<T> {
+        let mut node = self;
+        while let Some( front ) = node.front() {
+            node = front;
+        }
+        node
+    }
+
+    /// Returns the rightmost leaf of this node's subtree,
+    /// or itself if it has no child.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /( tr(3)/tr(4) );
+    /// assert_eq!( tree.root().last_leaf().data(), &4 );
+    /// assert_eq!( tr(5).root().last_leaf().data(), &5 );
+    /// ```
+    pub fn last_leaf( &self ) -> &Node<T> {
+        let mut node = self;
+        while let Some( back ) = node.back() {
+            node = back;
+        }
+        node
+    }
+
     /// Adds the tree as the first child.
     ///
     /// # Examples
@@ -411,6 +631,66 @@ impl<T> Node<T> {
         mem::forget( tree );
     }
 
+    /// Adds a leaf child holding the given data at the front of children list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::new(0);
+    /// tree.root_mut().push_front_data(2);
+    /// tree.root_mut().push_front_data(1);
+    /// assert_eq!( tree.to_string(), "0( 1 2 )" );
+    /// ```
+    pub fn push_front_data( &mut self, data: T ) { self.push_front( Tree::new( data )); }
+
+    /// Adds a leaf child holding the given data at the back of children list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::new(0);
+    /// tree.root_mut().push_back_data(1);
+    /// tree.root_mut().push_back_data(2);
+    /// assert_eq!( tree.to_string(), "0( 1 2 )" );
+    /// ```
+    pub fn push_back_data( &mut self, data: T ) { self.push_back( Tree::new( data )); }
+
+    /// Adds the tree as the last child, the same as `push_back()`, but checks it first.
+    /// Returns `Err(TreeError::Cycle)` if the tree's root is this node or one of its ancestors,
+    /// or `Err(TreeError::Attached)` if the tree's root still has a parent.
+    /// On error the links are left untouched and the tree is not dropped, since its nodes are owned elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1);
+    /// assert_eq!( tree.root_mut().try_append_child( tr(2) ), Ok(()) );
+    /// assert_eq!( tree.to_string(), "0( 1 2 )" );
+    /// ```
+    pub fn try_append_child( &mut self, tree: Tree<T> ) -> Result<(), TreeError> {
+        let root = tree.root().non_null();
+        let mut node = Some( self.non_null() );
+        while let Some( curr ) = node {
+            if curr == root {
+                mem::forget( tree );
+                return Err( TreeError::Cycle );
+            }
+            node = unsafe{ curr.as_ref().up };
+        }
+        if tree.root().up.is_some() {
+            mem::forget( tree );
+            return Err( TreeError::Attached );
+        }
+        self.push_back( tree );
+        Ok(())
+    }
+
     /// Removes and return the first child.
     ///
     /// # Examples
@@ -483,6 +763,135 @@ impl<T> Node<T> {
         }
     }
 
+    /// Inserts a new node with `data` between this node and its `nth` child,
+    /// the child becoming the only child of the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nth >= degree()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1) /tr(2) /tr(3);
+    /// tree.root_mut().insert_above_child( 1, 9 );
+    /// assert_eq!( tree.to_string(), "0( 1 9( 2 ) 3 )" );
+    /// assert_eq!( tree.root().node_count(), 5 );
+    /// ```
+    pub fn insert_above_child( &mut self, nth: usize, data: T ) {
+        let child = self.iter_mut().nth( nth ).expect( "insert_above_child: index out of bounds" );
+        let child = unsafe{ Pin::get_unchecked_mut( child )}.detach();
+        let mut tree = Tree::new( data );
+        tree.push_back( child );
+        self.insert_child( nth, tree );
+    }
+
+    /// Returns the first child whose data matches, or pushes back the tree made by `make` and returns its root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr("");
+    /// for _ in 0..3 {
+    ///     tree.root_mut().child_or_insert_with( |key| *key == "a", || tr("a") );
+    /// }
+    /// tree.root_mut().child_or_insert_with( |key| *key == "b", || tr("b") ).push_back( tr("c") );
+    /// assert_eq!( tree.to_string(), "( a b( c ) )" );
+    /// ```
+    pub fn child_or_insert_with<M,F>( &mut self, mut matches: M, make: F ) -> Pin<&mut Node<T>>
+        where M: FnMut(&T) -> bool
+            , F: FnOnce() -> Tree<T>
+    {
+        match self.iter().position( |child| matches( child.data() )) {
+            Some( index ) => self.iter_mut().nth( index ).unwrap(),
+            None => {
+                self.push_back( make() );
+                self.back_mut().unwrap()
+            },
+        }
+    }
+
+    /// Drops the last `k` children, or all of them if `k` exceeds the degree.
+    /// Sizes are updated once for the whole removal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::<i32>::from_tuple(( 0, 1, 2, 3, 4 ));
+    /// tree.root_mut().drop_back_n( 2 );
+    /// assert_eq!( tree.to_string(), "0( 1 2 )" );
+    /// assert_eq!( tree.node_count(), 3 );
+    /// tree.root_mut().drop_back_n( 5 );
+    /// assert_eq!( tree.to_string(), "0" );
+    /// ```
+    pub fn drop_back_n( &mut self, k: usize ) {
+        let k = k.min( self.degree() );
+        if k == 0 {
+            return;
+        }
+        unsafe {
+            let mut first = self.tail.unwrap();
+            let mut node_cnt = first.as_ref().size.descendants + 1;
+            for _ in 1..k {
+                first = first.as_ref().prev.unwrap();
+                node_cnt += first.as_ref().size.descendants + 1;
+            }
+            match first.as_ref().prev {
+                Some( mut new_tail ) => {
+                    new_tail.as_mut().next = None;
+                    first.as_mut().prev = None;
+                    self.tail = Some( new_tail );
+                },
+                None => {
+                    self.head = None;
+                    self.tail = None;
+                },
+            }
+            self.dec_sizes( k, node_cnt );
+
+            let mut node = Some( first );
+            while let Some( mut current ) = node {
+                node = current.as_ref().next;
+                current.as_mut().next = None;
+                drop( Tree::from_node( current ));
+            }
+        }
+    }
+
+    /// Inserts the tree as the child at `index`, shifting later children right.
+    /// Inserting at `index == degree()` appends the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > degree()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1) /tr(2);
+    /// tree.root_mut().insert_child( 1, tr(9) );
+    /// assert_eq!( tree.to_string(), "0( 1 9 2 )" );
+    /// tree.root_mut().insert_child( 3, tr(8) );
+    /// assert_eq!( tree.to_string(), "0( 1 9 2 8 )" );
+    /// ```
+    pub fn insert_child( &mut self, index: usize, tree: Tree<T> ) {
+        assert!( index <= self.degree(), "insert_child: index out of bounds" );
+        if index == self.degree() {
+            self.push_back( tree );
+        } else {
+            let child = self.iter_mut().nth( index ).unwrap();
+            unsafe{ Pin::get_unchecked_mut( child )}.insert_prev_sib( tree );
+        }
+    }
+
     /// Adds all the forest's trees at front of children list.
     ///
     /// # Examples
@@ -543,33 +952,970 @@ impl<T> Node<T> {
         }
     }
 
-    pub(crate) fn non_null( &self ) -> NonNull<Node<T>> {
-        unsafe{ NonNull::new_unchecked( self as *const _ as *mut Node<T> )}
-    }
-
-    pub(crate) fn set_head( &mut self, child: &Node<T> ) {
-        self.head = Some( child.non_null() );
+    /// Merges the sorted forest's trees into the sorted children list, keeping it sorted by root data, in O(n+m).
+    /// Children comparing equal keep the existing ones first.
+    /// The sizes of ancestors are updated once for the whole forest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1) /tr(3) /tr(5);
+    /// tree.root_mut().merge_children_sorted( -tr(2)-tr(4) );
+    /// assert_eq!( tree.to_string(), "0( 1 2 3 4 5 )" );
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2)/tr(6) ) /tr(9);
+    /// tree.front_mut().unwrap().merge_children_sorted( -tr(0) -( tr(3)/tr(4) ) -tr(7) );
+    /// assert_eq!( tree.to_string(), "0( 1( 0 2 3( 4 ) 6 7 ) 9 )" );
+    /// assert_eq!( tree.root().node_count(), 9 );
+    /// assert_eq!( tree.front().unwrap().degree(), 5 );
+    /// assert_eq!( tree.validate(), Ok(()) );
+    /// ```
+    pub fn merge_children_sorted( &mut self, mut forest: Forest<T> ) where T: Ord {
+        if forest.has_no_child() {
+            return;
+        }
+        forest.set_up( self );
+        let size = forest.root_().size;
+        let mut existing = self.head;
+        let mut incoming = forest.root_().head;
+        let mut last: Option<NonNull<Node<T>>> = None;
+        unsafe {
+            loop {
+                let from_existing = match( existing, incoming ) {
+                    (Some( a ), Some( b )) => a.as_ref().data() <= b.as_ref().data(),
+                    (Some( _ ), None     ) => true,
+                    (None     , Some( _ )) => false,
+                    (None     , None     ) => break,
+                };
+                let mut node = if from_existing { existing.unwrap() } else { incoming.unwrap() };
+                if from_existing {
+                    existing = node.as_ref().next;
+                } else {
+                    incoming = node.as_ref().next;
+                }
+                match last {
+                    Some( mut last ) => last.as_mut().connect_next( node.as_mut() ),
+                    None => {
+                        node.as_mut().prev = None;
+                        self.set_head( node.as_ref() );
+                    },
+                }
+                last = Some( node );
+            }
+            let mut tail = last.unwrap();
+            tail.as_mut().next = None;
+            self.set_tail( tail.as_ref() );
+        }
+        self.inc_sizes( size.degree, size.descendants );
+        forest.clear();
     }
 
-    pub(crate) fn set_tail( &mut self, child: &Node<T> ) {
-        self.tail = Some( child.non_null() );
+    /// Adds a leaf child for each data at the back of children list, in order.
+    /// The sizes of ancestors are updated once for all the leaves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1);
+    /// tree.root_mut().front_mut().unwrap().append_leaves( 0..100 );
+    /// assert_eq!( tree.front().unwrap().degree(), 100 );
+    /// assert_eq!( tree.node_count(), 102 );
+    /// assert_eq!( tree.front().unwrap().back().unwrap().data(), &99 );
+    /// ```
+    pub fn append_leaves<I:IntoIterator<Item=T>>( &mut self, items: I ) {
+        let mut forest = Forest::new();
+        items.into_iter().for_each( |data| forest.push_back( Tree::new( data )));
+        self.append( forest );
     }
 
-    pub(crate) fn set_up( &mut self, up: &Node<T> ) {
-        self.up = Some( up.non_null() );
+    /// Constructs a tree from each tuple notation and adds them at the back of children list, in order.
+    /// This is the way to build nodes having more children than a single tuple can hold.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::new(0);
+    /// tree.root_mut().append_each( vec![ (1,2), (3,4) ]);
+    /// assert_eq!( tree.to_string(), "0( 1( 2 ) 3( 4 ) )" );
+    ///
+    /// let mut tree = Tree::new(0);
+    /// tree.root_mut().append_each( 1..=40 );
+    /// assert_eq!( tree.root().degree(), 40 );
+    /// assert_eq!( tree.back().unwrap().data(), &40 );
+    /// ```
+    pub fn append_each<Tuple,Shape,I>( &mut self, tuples: I )
+        where I     : IntoIterator<Item=Tuple>
+            , Tuple : TupleTree<T,Shape>
+    {
+        for tuple in tuples {
+            self.push_back( Tree::from_tuple( tuple ));
+        }
     }
 
-    pub(crate) fn connect_next( &mut self, next: &mut Node<T> ) {
-        self.next = Some( next.non_null() );
-        next.prev = Some( self.non_null() );
+    /// Keeps the first `max` children and drops the rest, along with their descendants.
+    /// Only the direct children are affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(1) /tr(2) /( tr(3)/tr(5) ) /tr(4);
+    /// tree.root_mut().cap_children(2);
+    /// assert_eq!( tree.to_string(), "0( 1 2 )" );
+    /// assert_eq!( tree.root().node_count(), 3 );
+    /// ```
+    pub fn cap_children( &mut self, max: usize ) {
+        while self.degree() > max {
+            self.pop_back();
+        }
     }
 
-    pub(crate) fn inc_sizes( &mut self, degree: usize, node_cnt: usize ) {
-        self.size.degree += degree;
-        self.size.descendants += node_cnt;
-        let mut node = self.up;
-        while let Some( mut pnode ) = node {
-            unsafe {
+    /// Drops all the child nodes and adds the given trees as the new children, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /tr(9);
+    /// tree.root_mut().set_children( vec![ tr(1), tr(2) ]);
+    /// assert_eq!( tree.to_string(), "0( 1 2 )" );
+    /// assert_eq!( tree.root().node_count(), 3 );
+    /// ```
+    pub fn set_children<I:IntoIterator<Item=Tree<T>>>( &mut self, children: I ) {
+        while self.pop_front().is_some() {}
+        for child in children {
+            self.push_back( child );
+        }
+    }
+
+    /// Replaces the node with its only child, discarding the node's data.
+    /// The child's data and children take the place of the node's own.
+    /// Returns `Err(())` and leaves the node untouched if it does not have exactly one child.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// assert_eq!( tree.front_mut().unwrap().promote_only_child(), Ok(()) );
+    /// assert_eq!( tree.to_string(), "0( 2 3 )" );
+    /// assert_eq!( tree.root().node_count(), 3 );
+    /// assert_eq!( tree.front_mut().unwrap().promote_only_child(), Err(()) );
+    /// ```
+    #[allow( clippy::result_unit_err )]
+    pub fn promote_only_child( &mut self ) -> Result<(), ()> {
+        if self.degree() != 1 {
+            return Err(());
+        }
+        let mut child = self.pop_front().unwrap();
+        mem::swap( self.data_mut(), child.root_mut_().data_mut() );
+        self.append( child.abandon() );
+        Ok(())
+    }
+
+    /// Removes the node from its parent's children and links its own children at its position,
+    /// discarding the node's data.
+    /// Returns `Err(())` and leaves the node untouched if it is a root or a leaf;
+    /// use `detach()` to remove a leaf.
+    ///
+    /// The node is reused for the first hoisted child, so `self` refers to that child afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// assert_eq!( tree.front_mut().unwrap().splice_out(), Ok(()) );
+    /// assert_eq!( tree.to_string(), "0( 2 3 4 )" );
+    /// assert_eq!( tree.root().node_count(), 4 );
+    /// assert_eq!( tree.root_mut().splice_out(), Err(()) );
+    /// assert_eq!( tree.back_mut().unwrap().splice_out(), Err(()) );
+    /// ```
+    #[allow( clippy::result_unit_err )]
+    pub fn splice_out( &mut self ) -> Result<(), ()> {
+        if self.up.is_none() || self.has_no_child() {
+            return Err(());
+        }
+        let mut first = self.pop_front().unwrap();
+        let mut rest = Forest::new();
+        while let Some( child ) = self.pop_front() {
+            rest.push_back( child );
+        }
+        mem::swap( self.data_mut(), first.root_mut_().data_mut() );
+        self.append( first.abandon() );
+        while let Some( sib ) = rest.pop_back() {
+            self.insert_next_sib( sib );
+        }
+        Ok(())
+    }
+
+    /// Drops every descendant whose data fails the predicate, along with all its descendants.
+    /// The node itself is always kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2) ) /( tr(4)/tr(3) );
+    /// tree.root_mut().retain_recursive( |data| data % 2 == 0 );
+    /// assert_eq!( tree.to_string(), "0( 4 )" );
+    /// assert_eq!( tree.root().node_count(), 2 );
+    /// ```
+    pub fn retain_recursive<F:FnMut(&T)->bool>( &mut self, mut pred: F ) {
+        fn retain_recursive<T>( node: &mut Node<T>, pred: &mut dyn FnMut(&T)->bool ) {
+            for child in node.iter_mut() {
+                let child = unsafe{ Pin::get_unchecked_mut( child )};
+                if pred( child.data() ) {
+                    retain_recursive( child, pred );
+                } else {
+                    drop( child.detach() );
+                }
+            }
+        }
+        retain_recursive( self, &mut pred );
+    }
+
+    /// Drops every descendant leaf whose data satisfies `should_drop`, cascading upward:
+    /// a node left without children by the removal is dropped if it also satisfies `should_drop`.
+    /// The node itself is always kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(1) /( tr(0)/tr(0) ) /( tr(0)/tr(2) ) /tr(0);
+    /// tree.root_mut().prune_leaves_where( |data| *data == 0 );
+    /// assert_eq!( tree.to_string(), "1( 0( 2 ) )" );
+    /// assert_eq!( tree.root().node_count(), 3 );
+    /// ```
+    pub fn prune_leaves_where<F:FnMut(&T)->bool>( &mut self, mut should_drop: F ) {
+        fn prune_leaves_where<T>( node: &mut Node<T>, should_drop: &mut dyn FnMut(&T)->bool ) {
+            for child in node.iter_mut() {
+                let child = unsafe{ Pin::get_unchecked_mut( child )};
+                prune_leaves_where( child, should_drop );
+                if child.has_no_child() && should_drop( child.data() ) {
+                    drop( child.detach() );
+                }
+            }
+        }
+        prune_leaves_where( self, &mut should_drop );
+    }
+
+    /// Detaches every descendant whose data matches the predicate, along with all its descendants,
+    /// and returns them as a forest in pre-order. Matched subtrees are not searched further.
+    /// The node itself is never extracted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(3) ) /( tr(2)/tr(5)/tr(6) ) /tr(7);
+    /// let forest = tree.root_mut().extract_matching( |data| data % 2 == 1 );
+    /// assert_eq!( forest.to_string(), "( 1( 3 ) 5 7 )" );
+    /// assert_eq!( tree.to_string(), "0( 2( 6 ) )" );
+    /// assert_eq!( tree.root().node_count(), 3 );
+    /// ```
+    pub fn extract_matching<F:FnMut(&T)->bool>( &mut self, mut pred: F ) -> Forest<T> {
+        fn extract_matching<T>( node: &mut Node<T>, pred: &mut dyn FnMut(&T)->bool, forest: &mut Forest<T> ) {
+            for child in node.iter_mut() {
+                let child = unsafe{ Pin::get_unchecked_mut( child )};
+                if pred( child.data() ) {
+                    forest.push_back( child.detach() );
+                } else {
+                    extract_matching( child, pred, forest );
+                }
+            }
+        }
+        let mut forest = Forest::new();
+        extract_matching( self, &mut pred, &mut forest );
+        forest
+    }
+
+    /// Makes a tree of the same shape, whose data are the `Display` strings of this subtree's data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, tr};
+    ///
+    /// let tree: Tree<String> = ( tr(1)/tr(2) ).root().to_string_tree();
+    /// assert_eq!( tree.to_string(), "1( 2 )" );
+    /// assert_eq!( tree.root().data(), "1" );
+    /// ```
+    pub fn to_string_tree( &self ) -> Tree<String> where T: Display {
+        let mut tree = Tree::new( format!( "{}", self.data() ));
+        for child in self.iter() {
+            tree.push_back( child.to_string_tree() );
+        }
+        tree
+    }
+
+    /// Formats the subtree as an S-expression, a leaf as its data and a branched node as `(data children...)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// assert_eq!( tree.root().to_sexp(), "(0 (1 2 3) 4)" );
+    /// assert_eq!( tr(5).root().to_sexp(), "5" );
+    /// ```
+    pub fn to_sexp( &self ) -> String where T: Display {
+        if self.has_no_child() {
+            format!( "{}", self.data() )
+        } else {
+            let mut sexp = format!( "({}", self.data() );
+            for child in self.iter() {
+                sexp.push( ' ' );
+                sexp.push_str( &child.to_sexp() );
+            }
+            sexp.push( ')' );
+            sexp
+        }
+    }
+
+    /// Formats the subtree as JSON objects of the form `{"data":<data>,"children":[...]}`.
+    /// The data are written as bare tokens through `Display`, without quoting or escaping,
+    /// so the caller must ensure they are valid JSON values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /tr(1);
+    /// assert_eq!( tree.root().to_json_string(), r#"{"data":0,"children":[{"data":1,"children":[]}]}"# );
+    /// ```
+    pub fn to_json_string( &self ) -> String where T: Display {
+        let mut json = format!( r#"{{"data":{},"children":["#, self.data() );
+        for (index, child) in self.iter().enumerate() {
+            if index != 0 {
+                json.push( ',' );
+            }
+            json.push_str( &child.to_json_string() );
+        }
+        json.push_str( "]}" );
+        json
+    }
+
+    /// Formats the subtree with custom brackets and separator: a leaf as its data,
+    /// and a branched node as its data followed by `open`, its children separated by `sep`, then `close`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// assert_eq!( tree.root().display_with( "[", "]", "," ), "0[1[2],3]" );
+    /// assert_eq!( ( tr(0)/tr(1)/tr(2) ).root().display_with( "[", "]", "," ), "0[1,2]" );
+    /// ```
+    pub fn display_with( &self, open: &str, close: &str, sep: &str ) -> String where T: Display {
+        let mut text = format!( "{}", self.data() );
+        if !self.has_no_child() {
+            text.push_str( open );
+            for (index, child) in self.iter().enumerate() {
+                if index != 0 {
+                    text.push_str( sep );
+                }
+                text.push_str( &child.display_with( open, close, sep ));
+            }
+            text.push_str( close );
+        }
+        text
+    }
+
+    /// Clones the node and those descendants whose data satisfy the predicate.
+    /// A failing node is skipped along with all its descendants.
+    /// Returns `None` if the node itself fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /( tr(9)/tr(3) );
+    /// let cloned = tree.root().clone_filtered( |data| *data < 5 ).unwrap();
+    /// assert_eq!( cloned.to_string(), "0( 1( 2 ) )" );
+    /// assert_eq!( tree.root().clone_filtered( |data| *data > 0 ), None );
+    /// ```
+    pub fn clone_filtered<F:FnMut(&T)->bool>( &self, mut pred: F ) -> Option<Tree<T>>
+        where T: Clone
+    {
+        fn clone_filtered<T:Clone>( node: &Node<T>, pred: &mut dyn FnMut(&T)->bool ) -> Option<Tree<T>> {
+            if pred( node.data() ) {
+                let mut tree = Tree::new( node.data().clone() );
+                for child in node.iter() {
+                    if let Some( child ) = clone_filtered( child, pred ) {
+                        tree.push_back( child );
+                    }
+                }
+                Some( tree )
+            } else {
+                None
+            }
+        }
+        clone_filtered( self, &mut pred )
+    }
+
+    /// Applies `f` to the data of the node and all its descendants, in pre-order.
+    /// Walks the subtree iteratively, so deep trees do not overflow the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2) );
+    /// tree.root_mut().map_in_place( |data| *data += 1 );
+    /// assert_eq!( tree.to_string(), "1( 2( 3 ) )" );
+    /// ```
+    pub fn map_in_place<F:FnMut(&mut T)>( &mut self, mut f: F ) {
+        let mut stack = vec![ self.non_null() ];
+        while let Some( mut node ) = stack.pop() {
+            let node = unsafe{ node.as_mut() };
+            f( node.data_mut() );
+            let len = stack.len();
+            stack.extend( node.iter_mut().map( |child| child.non_null() ));
+            stack[ len.. ].reverse();
+        }
+    }
+
+    /// Reduces the subtree to a single value in post-order: each node's value is computed by `f`
+    /// from its data and the values of its children, and the node's own value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(1) /( tr(2)/tr(3) ) /tr(4);
+    /// assert_eq!( tree.root().reduce( |data, children| data + children.iter().sum::<i32>() ), 10 );
+    /// ```
+    pub fn reduce<F:FnMut(&T,&[T])->T>( &self, mut f: F ) -> T {
+        fn reduce<T>( node: &Node<T>, f: &mut dyn FnMut(&T,&[T])->T ) -> T {
+            let children = node.iter().map( |child| reduce( child, f )).collect::<Vec<_>>();
+            f( node.data(), &children )
+        }
+        reduce( self, &mut f )
+    }
+
+    /// Calls `f` with the depth and the mutable data of the node and all its descendants, in pre-order.
+    /// The node itself is at depth 0.
+    ///
+    /// A callback is taken instead of returning an iterator, because mutable references to
+    /// the data could not be yielded while the iterator keeps walking through the nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(0)/tr(0) ) /tr(0);
+    /// tree.root_mut().iter_mut_with_depth( |depth, data| *data = depth );
+    /// assert_eq!( tree.to_string(), "0( 1( 2 ) 1 )" );
+    /// ```
+    pub fn iter_mut_with_depth<F:FnMut(usize,&mut T)>( &mut self, mut f: F ) {
+        fn iter_mut_with_depth<T>( node: &mut Node<T>, depth: usize, f: &mut dyn FnMut(usize,&mut T) ) {
+            f( depth, node.data_mut() );
+            for child in node.iter_mut() {
+                iter_mut_with_depth( unsafe{ Pin::get_unchecked_mut( child )}, depth+1, f );
+            }
+        }
+        iter_mut_with_depth( self, 0, &mut f );
+    }
+
+    /// Collects mutable references to the data of the node and all its descendants, in pre-order.
+    /// The references are disjoint and can be processed independently, e.g. in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::<i32>::from_tuple(( 0, (1,2), (3,4) ));
+    /// tree.root_mut().data_slices_mut().into_iter().for_each( |data| *data *= 2 );
+    /// assert_eq!( tree.to_string(), "0( 2( 4 ) 6( 8 ) )" );
+    /// ```
+    pub fn data_slices_mut( &mut self ) -> Vec<&mut T> {
+        let mut datas = Vec::with_capacity( self.node_count() );
+        let mut stack = Vec::new();
+        stack.push( self.non_null() );
+        while let Some( node ) = stack.pop() {
+            unsafe {
+                let node = node.as_ptr();
+                let mut child = (*node).tail;
+                while let Some( curr ) = child {
+                    stack.push( curr );
+                    child = curr.as_ref().prev;
+                }
+                datas.push( (*node).data.as_mut() );
+            }
+        }
+        datas
+    }
+
+    /// Feeds the degree of each node into the hasher in pre-order,
+    /// ignoring the data, so that trees of the same shape hash equally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use trees::tr;
+    ///
+    /// let shape_hash = |tree: &trees::Tree<i32>| {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     tree.root().hash_structure_only( &mut hasher );
+    ///     hasher.finish()
+    /// };
+    ///
+    /// let a = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let b = tr(4) /( tr(5)/tr(6) ) /tr(7);
+    /// let c = tr(0) /tr(1) /( tr(2)/tr(3) );
+    /// assert_eq!( shape_hash( &a ), shape_hash( &b ));
+    /// assert_ne!( shape_hash( &a ), shape_hash( &c ));
+    /// ```
+    pub fn hash_structure_only<H:Hasher>( &self, state: &mut H ) {
+        self.degree().hash( state );
+        for child in self.iter() {
+            child.hash_structure_only( state );
+        }
+    }
+
+    /// Hashes the data and shape of the subtree with the default hasher.
+    /// Equal subtrees produce equal hashes, so the result can key a dedup index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /( tr(1)/tr(2) ) /( tr(1)/tr(2)/tr(3) );
+    /// let mut iter = tree.iter();
+    /// let a = iter.next().unwrap().subtree_hash();
+    /// let b = iter.next().unwrap().subtree_hash();
+    /// let c = iter.next().unwrap().subtree_hash();
+    /// assert_eq!( a, b );
+    /// assert_ne!( a, c );
+    /// assert_ne!(( tr(0)/tr(1)/tr(2) ).root().subtree_hash(), ( tr(0)/( tr(1)/tr(2) )).root().subtree_hash() );
+    /// ```
+    #[cfg(not(feature="no_std"))]
+    pub fn subtree_hash( &self ) -> u64 where T: Hash {
+        fn feed<T:Hash>( node: &Node<T>, state: &mut DefaultHasher ) {
+            node.data().hash( state );
+            node.degree().hash( state );
+            for child in node.iter() {
+                feed( child, state );
+            }
+        }
+        let mut hasher = DefaultHasher::new();
+        feed( self, &mut hasher );
+        hasher.finish()
+    }
+
+    /// Returns `true` if both subtrees have the same shape, ignoring the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(1) /tr(2) /tr(3);
+    /// assert!( tree.root().same_shape( ( tr('a')/tr('b')/tr('c') ).root() ));
+    /// assert!( !tree.root().same_shape( ( tr(1)/tr(2) ).root() ));
+    /// ```
+    pub fn same_shape<U>( &self, other: &Node<U> ) -> bool {
+        self.degree() == other.degree()
+            && self.iter().zip( other.iter() ).all( |(a, b)| a.same_shape( b ))
+    }
+
+    /// Returns `true` if both subtrees have the same shape and `eq` holds for each pair of data,
+    /// stopping at the first mismatch. This generalizes `PartialEq`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let a = tr(1.0_f64) /tr(2.0) /tr(3.0);
+    /// let b = tr(1.0_f64) /tr(2.000001) /tr(2.999999);
+    /// assert!( a.root().eq_with( b.root(), |x, y| ( x - y ).abs() < 1e-3 ));
+    /// assert!( !a.root().eq_with( b.root(), |x, y| x == y ));
+    /// assert!( !a.root().eq_with( ( tr(1.0)/tr(2.0) ).root(), |x, y| ( x - y ).abs() < 1e-3 ));
+    /// ```
+    pub fn eq_with<F:FnMut(&T,&T)->bool>( &self, other: &Node<T>, mut eq: F ) -> bool {
+        fn eq_with<T>( a: &Node<T>, b: &Node<T>, eq: &mut dyn FnMut(&T,&T)->bool ) -> bool {
+            a.degree() == b.degree()
+                && eq( a.data(), b.data() )
+                && a.iter().zip( b.iter() ).all( |(a, b)| eq_with( a, b, eq ))
+        }
+        eq_with( self, other, &mut eq )
+    }
+
+    /// Clones the largest top-down part shared by both subtrees.
+    /// Children are paired by position, and a pair is kept only if their data are equal,
+    /// otherwise that branch stops there.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data of both nodes are not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let a = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let b = tr(0) /( tr(1)/tr(9) ) /tr(3);
+    /// assert_eq!( a.root().common_prefix( b.root() ).to_string(), "0( 1 3 )" );
+    /// ```
+    pub fn common_prefix( &self, other: &Node<T> ) -> Tree<T> where T: Clone + PartialEq {
+        assert!( self.data() == other.data(), "common_prefix: the nodes have different data" );
+        let mut tree = Tree::new( self.data().clone() );
+        self.iter().zip( other.iter() )
+            .filter( |(a, b)| a.data() == b.data() )
+            .for_each( |(a, b)| tree.push_back( a.common_prefix( b )));
+        tree
+    }
+
+    /// Returns `true` if every non-leaf node in the subtree has `degree` within `[min, max]`.
+    /// Leaf nodes are exempt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// assert!( tree.root().satisfies_degree_bounds( 2, 3 ));
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// assert!( !tree.root().satisfies_degree_bounds( 2, 3 ));
+    /// ```
+    pub fn satisfies_degree_bounds( &self, min: usize, max: usize ) -> bool {
+        self.has_no_child()
+            || min <= self.degree() && self.degree() <= max
+            && self.iter().all( |child| child.satisfies_degree_bounds( min, max ))
+    }
+
+    /// Returns the number of edges on the longest downward path from this node, 0 for a leaf.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2)/tr(3) );
+    /// assert_eq!( tree.root().longest_path_len(), 2 );
+    /// assert_eq!( tr(0).root().longest_path_len(), 0 );
+    /// ```
+    pub fn longest_path_len( &self ) -> usize {
+        self.iter()
+            .map( |child| child.longest_path_len() + 1 )
+            .max()
+            .unwrap_or( 0 )
+    }
+
+    /// Returns the height of the first child's subtree minus the height of the last child's subtree.
+    /// Heights count nodes, so a leaf child has height 1, and a missing child has height 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1) /( tr(2)/tr(3) )) /tr(4);
+    /// assert_eq!( tree.root().balance_factor(), 2 );
+    /// assert_eq!( tree.front().unwrap().balance_factor(), 0 );
+    /// assert_eq!( tr(5).root().balance_factor(), 0 );
+    /// ```
+    pub fn balance_factor( &self ) -> i64 {
+        let height = |node: Option<&Node<T>>| node.map_or( 0, |node| node.longest_path_len() as i64 + 1 );
+        height( self.front() ) - height( self.back() )
+    }
+
+    /// Groups the node and its descendants by depth, in breadth-first order.
+    /// Entry `d` lists the nodes at depth `d`, the node itself being at depth 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let levels = tree.root().levels().into_iter()
+    ///     .map( |level| level.into_iter().map( |node| *node.data() ).collect::<Vec<_>>() )
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!( levels, vec![ vec![0], vec![1,3], vec![2] ]);
+    /// ```
+    pub fn levels( &self ) -> Vec<Vec<&Node<T>>> {
+        let mut levels = Vec::new();
+        let mut level = vec![ self ];
+        while !level.is_empty() {
+            let next = level.iter().flat_map( |node| node.iter() ).collect::<Vec<_>>();
+            levels.push( level );
+            level = next;
+        }
+        levels
+    }
+
+    /// Returns the node in the subtree, including itself, with the minimum key.
+    /// If several nodes are equally minimum, the first one in pre-order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(1) /( tr(5)/tr(2) ) /tr(3);
+    /// assert_eq!( tree.root().min_by_key( |data| *data ).unwrap().data(), &1 );
+    /// assert_eq!( tree.root().min_by_key( |data| ( *data - 3_i32 ).abs() ).unwrap().data(), &3 );
+    /// ```
+    pub fn min_by_key<K:Ord, F:FnMut(&T)->K>( &self, mut f: F ) -> Option<&Node<T>> {
+        self.subtree_preorder().min_by_key( |node| f( node.data() ))
+    }
+
+    /// Returns the node in the subtree, including itself, with the maximum key.
+    /// If several nodes are equally maximum, the last one in pre-order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(1) /( tr(5)/tr(2) ) /tr(3);
+    /// let node = tree.root().max_by_key( |data| *data ).unwrap();
+    /// assert_eq!( node.data(), &5 );
+    /// assert_eq!( node.to_string(), "5( 2 )" );
+    /// ```
+    pub fn max_by_key<K:Ord, F:FnMut(&T)->K>( &self, mut f: F ) -> Option<&Node<T>> {
+        self.subtree_preorder().max_by_key( |node| f( node.data() ))
+    }
+
+    /// Returns the minimum and maximum data in the subtree, including itself, in a single traversal.
+    /// Always returns `Some` since the node itself counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(3) /( tr(1)/tr(5) ) /tr(2);
+    /// assert_eq!( tree.root().data_range(), Some(( &1, &5 )));
+    /// assert_eq!( tr(7).root().data_range(), Some(( &7, &7 )));
+    /// ```
+    pub fn data_range( &self ) -> Option<(&T, &T)> where T: Ord {
+        let mut nodes = self.subtree_preorder();
+        let first = nodes.next()?.data();
+        Some( nodes.fold( (first, first), |(min, max), node| {
+            let data = node.data();
+            (min.min( data ), max.max( data ))
+        }))
+    }
+
+    /// Tests if any data in the subtree, including itself, matches the predicate.
+    /// Stops at the first match in depth-first order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(2) /( tr(4)/tr(6) );
+    /// assert!( tree.root().any( |x| *x > 5 ));
+    /// assert!( !tree.root().any( |x| x % 2 == 1 ));
+    /// ```
+    pub fn any<F:FnMut(&T)->bool>( &self, mut f: F ) -> bool {
+        self.subtree_preorder().any( |node| f( node.data() ))
+    }
+
+    /// Tests if all data in the subtree, including itself, match the predicate.
+    /// Stops at the first mismatch in depth-first order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(2) /( tr(4)/tr(6) );
+    /// assert!( tree.root().all( |x| x % 2 == 0 ));
+    /// assert!( !tree.root().all( |x| *x > 2 ));
+    /// ```
+    pub fn all<F:FnMut(&T)->bool>( &self, mut f: F ) -> bool {
+        self.subtree_preorder().all( |node| f( node.data() ))
+    }
+
+    /// Provides a depth-first iterator over the subtree, including itself, pairing each node with its path,
+    /// the child indices leading from this node to it. This node has an empty path.
+    /// Notice that each item allocates its own path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let (path, _) = tree.root().iter_with_paths().find( |(_,node)| *node.data() == 2 ).unwrap();
+    /// assert_eq!( path, vec![ 0, 0 ]);
+    /// assert_eq!( tree.root().iter_with_paths().map( |(path,_)| path.len() ).collect::<Vec<_>>(), vec![ 0, 1, 2, 1 ]);
+    /// ```
+    pub fn iter_with_paths( &self ) -> impl Iterator<Item=(Vec<usize>, &Node<T>)> {
+        let mut stack = vec![ (Vec::new(), self) ];
+        iter::from_fn( move || {
+            let (path, node) = stack.pop()?;
+            let len = stack.len();
+            stack.extend( node.iter().enumerate().map( |(index, child)| {
+                let mut child_path = path.clone();
+                child_path.push( index );
+                (child_path, child)
+            }));
+            stack[ len.. ].reverse();
+            Some(( path, node ))
+        })
+    }
+
+    /// Returns `true` if every non-leaf node in the subtree, including itself, has exactly `k` children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// assert!( ( tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4) ).root().is_full_kary( 2 ));
+    /// assert!( !( tr(0) /( tr(1)/tr(2) ) /tr(3) ).root().is_full_kary( 2 ));
+    /// assert!( tr(0).root().is_full_kary( 3 ));
+    /// ```
+    pub fn is_full_kary( &self, k: usize ) -> bool {
+        self.subtree_preorder().all( |node| node.has_no_child() || node.degree() == k )
+    }
+
+    /// Counts the nodes in the subtree, including itself, by their degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let histogram = tree.root().degree_histogram();
+    /// assert_eq!( histogram.into_iter().collect::<Vec<_>>(), vec![ (0,2), (1,1), (2,1) ]);
+    /// ```
+    pub fn degree_histogram( &self ) -> BTreeMap<usize,usize> {
+        let mut histogram = BTreeMap::new();
+        self.subtree_preorder().for_each( |node| *histogram.entry( node.degree() ).or_insert( 0 ) += 1 );
+        histogram
+    }
+
+    /// Counts the nodes in the subtree, including itself, which root a subtree equal to `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /( tr(3) /( tr(1)/tr(2) )) /( tr(1)/tr(2)/tr(4) );
+    /// assert_eq!( tree.root().count_matching_subtrees( ( tr(1)/tr(2) ).root() ), 2 );
+    /// assert_eq!( tree.root().count_matching_subtrees( tr(2).root() ), 3 );
+    /// ```
+    pub fn count_matching_subtrees( &self, pattern: &Node<T> ) -> usize where T: PartialEq {
+        self.subtree_preorder().filter( |node| *node == pattern ).count()
+    }
+
+    /// Provides an iterator over the `(parent, child)` data pairs of the subtree.
+    /// Parents are visited in pre-order, each yielding the edges to its children in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) );
+    /// assert_eq!( tree.root().edges().collect::<Vec<_>>(), vec![ (&0,&1), (&1,&2) ]);
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// assert_eq!( tree.root().edges().collect::<Vec<_>>(), vec![ (&0,&1), (&0,&3), (&1,&2) ]);
+    /// ```
+    pub fn edges( &self ) -> impl Iterator<Item=(&T,&T)> {
+        self.subtree_preorder()
+            .flat_map( |parent| parent.iter().map( move |child| (parent.data(), child.data()) ))
+    }
+
+    /// Clones the data of the node and all its descendants into `out`, in pre-order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use trees::tr;
+    ///
+    /// let tree = tr(1) /( tr(2)/tr(2) );
+    /// let mut set = HashSet::new();
+    /// tree.root().collect_into( &mut set );
+    /// assert_eq!( set.len(), 2 );
+    ///
+    /// let mut vec = vec![ 0 ];
+    /// tree.root().collect_into( &mut vec );
+    /// assert_eq!( vec, vec![ 0, 1, 2, 2 ]);
+    /// ```
+    pub fn collect_into<C:Extend<T>>( &self, out: &mut C ) where T: Clone {
+        out.extend( self.subtree_preorder().map( |node| node.data().clone() ));
+    }
+
+    // Iterates over the node and all its descendants in pre-order.
+    pub(crate) fn subtree_preorder( &self ) -> impl Iterator<Item=&Node<T>> {
+        let mut stack = vec![ self ];
+        iter::from_fn( move || {
+            let node = stack.pop()?;
+            let len = stack.len();
+            stack.extend( node.iter() );
+            stack[ len.. ].reverse();
+            Some( node )
+        })
+    }
+
+    pub(crate) fn non_null( &self ) -> NonNull<Node<T>> {
+        unsafe{ NonNull::new_unchecked( self as *const _ as *mut Node<T> )}
+    }
+
+    pub(crate) fn set_head( &mut self, child: &Node<T> ) {
+        self.head = Some( child.non_null() );
+    }
+
+    pub(crate) fn set_tail( &mut self, child: &Node<T> ) {
+        self.tail = Some( child.non_null() );
+    }
+
+    pub(crate) fn set_up( &mut self, up: &Node<T> ) {
+        self.up = Some( up.non_null() );
+    }
+
+    pub(crate) fn connect_next( &mut self, next: &mut Node<T> ) {
+        self.next = Some( next.non_null() );
+        next.prev = Some( self.non_null() );
+    }
+
+    pub(crate) fn inc_sizes( &mut self, degree: usize, node_cnt: usize ) {
+        self.size.degree += degree;
+        self.size.descendants += node_cnt;
+        let mut node = self.up;
+        while let Some( mut pnode ) = node {
+            unsafe {
                 pnode.as_mut().size.descendants += node_cnt;
                 node = pnode.as_ref().up;
             }
@@ -601,6 +1947,29 @@ impl_debug_display_for_node!( Node, iter, data() );
 impl_order_relations_for_node!( Node, iter, data() );
 impl_hash_for_node!( Node, iter, data() );
 
+#[cfg( test )]
+mod tests {
+    use super::*;
+    use crate::tr;
+
+    #[test] fn try_append_child_rejects_own_subtree() {
+        let mut tree = tr(0) /( tr(1)/tr(2) );
+
+        let alias = Tree{ root: tree.root().non_null(), mark: PhantomData };
+        assert_eq!( tree.root_mut().try_append_child( alias ), Err( TreeError::Cycle ));
+
+        let alias = Tree{ root: tree.root().non_null(), mark: PhantomData };
+        let mut child = tree.front_mut().unwrap();
+        assert_eq!( child.try_append_child( alias ), Err( TreeError::Cycle ));
+
+        let alias = Tree{ root: tree.front().unwrap().non_null(), mark: PhantomData };
+        assert_eq!( tree.root_mut().try_append_child( alias ), Err( TreeError::Attached ));
+
+        assert_eq!( tree.to_string(), "0( 1( 2 ) )" );
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+}
+
 #[cfg( miri )]
 mod miri_tests {
     #[test] fn has_no_child() {
@@ -625,6 +1994,12 @@ mod miri_tests {
         assert_eq!( root.degree(), 2 );
     }
 
+    #[test] fn child_count() {
+        use crate::tr;
+        let tree = tr(0) /tr(1) /tr(2);
+        assert_eq!( tree.root().child_count(), 2 );
+    }
+
     #[test] fn node_count() {
         use crate::Tree;
 
@@ -689,6 +2064,40 @@ mod miri_tests {
         assert_eq!( tree.to_string(), "0( 1( 20 30 ) )" );
     }
 
+    #[test] fn at_path() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+        assert_eq!( tree.root().at_path( &[0,1] ).unwrap().data(), &3 );
+        assert_eq!( tree.root().at_path( &[] ).unwrap().data(), &0 );
+        assert!( tree.root().at_path( &[1,0] ).is_none() );
+    }
+
+    #[test] fn at_path_mut() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+        *tree.root_mut().at_path_mut( &[0,1] ).unwrap().data_mut() = 9;
+        assert_eq!( tree.to_string(), "0( 1( 2 9 ) 4 )" );
+        assert!( tree.root_mut().at_path_mut( &[2] ).is_none() );
+    }
+
+    #[test] fn first_leaf() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /( tr(3)/tr(4) );
+        assert_eq!( tree.root().first_leaf().data(), &2 );
+        assert_eq!( tr(5).root().first_leaf().data(), &5 );
+    }
+
+    #[test] fn last_leaf() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /( tr(3)/tr(4) );
+        assert_eq!( tree.root().last_leaf().data(), &4 );
+        assert_eq!( tr(5).root().last_leaf().data(), &5 );
+    }
+
     #[test] fn push_front() {
         use crate::Tree;
 
@@ -709,6 +2118,32 @@ mod miri_tests {
         assert_eq!( tree.to_string(), "0( 1 2 )" );
     }
 
+    #[test] fn push_front_data() {
+        use crate::Tree;
+
+        let mut tree = Tree::new(0);
+        tree.root_mut().push_front_data(2);
+        tree.root_mut().push_front_data(1);
+        assert_eq!( tree.to_string(), "0( 1 2 )" );
+    }
+
+    #[test] fn push_back_data() {
+        use crate::Tree;
+
+        let mut tree = Tree::new(0);
+        tree.root_mut().push_back_data(1);
+        tree.root_mut().push_back_data(2);
+        assert_eq!( tree.to_string(), "0( 1 2 )" );
+    }
+
+    #[test] fn try_append_child() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1);
+        assert_eq!( tree.root_mut().try_append_child( tr(2) ), Ok(()) );
+        assert_eq!( tree.to_string(), "0( 1 2 )" );
+    }
+
     #[test] fn pop_front() {
         use crate::Tree;
 
@@ -756,4 +2191,480 @@ mod miri_tests {
         tree.root_mut().append( forest );
         assert_eq!( tree.to_string(), "0( 1 2 3 4 )" );
     }
+
+    #[test] fn is_same() {
+        use crate::tr;
+
+        let tree = tr(0) /tr(1) /tr(1);
+        let first = tree.front().unwrap();
+        assert!( first.is_same( first ));
+        assert!( !first.is_same( tree.back().unwrap() ));
+    }
+
+    #[test] fn is_ancestor_of() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let one = tree.front().unwrap();
+        let two = one.front().unwrap();
+        let three = tree.back().unwrap();
+        assert!( tree.root().is_ancestor_of( two ));
+        assert!( one.is_ancestor_of( two ));
+        assert!( !one.is_ancestor_of( three ));
+        assert!( !one.is_ancestor_of( one ));
+    }
+
+    #[test] fn nth_ancestor() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) );
+        let two = tree.front().unwrap().front().unwrap();
+        assert!( two.nth_ancestor( 0 ).unwrap().is_same( two ));
+        assert!( two.nth_ancestor( 2 ).unwrap().is_same( tree.root() ));
+        assert!( two.nth_ancestor( 3 ).is_none() );
+    }
+
+    #[test] fn next_in_preorder() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let mut node = Some( tree.root() );
+        let mut visited = Vec::new();
+        while let Some( curr ) = node {
+            visited.push( *curr.data() );
+            node = curr.next_in_preorder();
+        }
+        assert_eq!( visited, vec![ 0, 1, 2, 3 ]);
+    }
+
+    #[test] fn insert_child() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1) /tr(2);
+        tree.root_mut().insert_child( 1, tr(9) );
+        assert_eq!( tree.to_string(), "0( 1 9 2 )" );
+        tree.root_mut().insert_child( 3, tr(8) );
+        assert_eq!( tree.to_string(), "0( 1 9 2 8 )" );
+    }
+
+    #[test] fn siblings() {
+        use crate::tr;
+
+        let tree = tr(0) /tr(1) /tr(2) /tr(3);
+        let two = tree.iter().nth(1).unwrap();
+        assert_eq!( two.siblings().map( |node| *node.data() ).collect::<Vec<_>>(), vec![ 1, 2, 3 ]);
+        assert_eq!( tree.root().siblings().count(), 1 );
+    }
+
+    #[test] fn for_each_child_mut() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(9) /tr(9) /tr(9);
+        tree.root_mut().for_each_child_mut( |index, mut child| *child.data_mut() = index );
+        assert_eq!( tree.to_string(), "0( 0 1 2 )" );
+    }
+
+    #[test] fn insert_above_child() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1) /tr(2) /tr(3);
+        tree.root_mut().insert_above_child( 1, 9 );
+        assert_eq!( tree.to_string(), "0( 1 9( 2 ) 3 )" );
+        assert_eq!( tree.root().node_count(), 5 );
+    }
+
+    #[test] fn child_or_insert_with() {
+        use crate::tr;
+
+        let mut tree = tr("");
+        for _ in 0..3 {
+            tree.root_mut().child_or_insert_with( |key| *key == "a", || tr("a") );
+        }
+        tree.root_mut().child_or_insert_with( |key| *key == "b", || tr("b") ).push_back( tr("c") );
+        assert_eq!( tree.to_string(), "( a b( c ) )" );
+    }
+
+    #[test] fn drop_back_n() {
+        use crate::Tree;
+
+        let mut tree = Tree::<i32>::from_tuple(( 0, 1, 2, 3, 4 ));
+        tree.root_mut().drop_back_n( 2 );
+        assert_eq!( tree.to_string(), "0( 1 2 )" );
+        assert_eq!( tree.node_count(), 3 );
+        tree.root_mut().drop_back_n( 5 );
+        assert_eq!( tree.to_string(), "0" );
+    }
+
+    #[test] fn merge_children_sorted() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1) /tr(3) /tr(5);
+        tree.root_mut().merge_children_sorted( -tr(2)-tr(4) );
+        assert_eq!( tree.to_string(), "0( 1 2 3 4 5 )" );
+
+        let mut tree = tr(0) /( tr(1)/tr(2)/tr(6) ) /tr(9);
+        tree.front_mut().unwrap().merge_children_sorted( -tr(0) -( tr(3)/tr(4) ) -tr(7) );
+        assert_eq!( tree.to_string(), "0( 1( 0 2 3( 4 ) 6 7 ) 9 )" );
+        assert_eq!( tree.root().node_count(), 9 );
+        assert_eq!( tree.front().unwrap().degree(), 5 );
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+
+    #[test] fn append_leaves() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1);
+        tree.root_mut().front_mut().unwrap().append_leaves( 0..100 );
+        assert_eq!( tree.front().unwrap().degree(), 100 );
+        assert_eq!( tree.node_count(), 102 );
+        assert_eq!( tree.front().unwrap().back().unwrap().data(), &99 );
+    }
+
+    #[test] fn append_each() {
+        use crate::Tree;
+
+        let mut tree = Tree::new(0);
+        tree.root_mut().append_each( vec![ (1,2), (3,4) ]);
+        assert_eq!( tree.to_string(), "0( 1( 2 ) 3( 4 ) )" );
+
+        let mut tree = Tree::new(0);
+        tree.root_mut().append_each( 1..=40 );
+        assert_eq!( tree.root().degree(), 40 );
+        assert_eq!( tree.back().unwrap().data(), &40 );
+    }
+
+    #[test] fn cap_children() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1) /tr(2) /( tr(3)/tr(5) ) /tr(4);
+        tree.root_mut().cap_children(2);
+        assert_eq!( tree.to_string(), "0( 1 2 )" );
+        assert_eq!( tree.root().node_count(), 3 );
+    }
+
+    #[test] fn set_children() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(9);
+        tree.root_mut().set_children( vec![ tr(1), tr(2) ]);
+        assert_eq!( tree.to_string(), "0( 1 2 )" );
+        assert_eq!( tree.root().node_count(), 3 );
+    }
+
+    #[test] fn promote_only_child() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        assert_eq!( tree.front_mut().unwrap().promote_only_child(), Ok(()) );
+        assert_eq!( tree.to_string(), "0( 2 3 )" );
+        assert_eq!( tree.root().node_count(), 3 );
+        assert_eq!( tree.front_mut().unwrap().promote_only_child(), Err(()) );
+    }
+
+    #[test] fn splice_out() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+        assert_eq!( tree.front_mut().unwrap().splice_out(), Ok(()) );
+        assert_eq!( tree.to_string(), "0( 2 3 4 )" );
+        assert_eq!( tree.root().node_count(), 4 );
+        assert_eq!( tree.root_mut().splice_out(), Err(()) );
+        assert_eq!( tree.back_mut().unwrap().splice_out(), Err(()) );
+
+        let mut tree = tr(0) /( tr(1)/( tr(2)/tr(5) )/tr(3) ) /tr(4);
+        assert_eq!( tree.front_mut().unwrap().splice_out(), Ok(()) );
+        assert_eq!( tree.to_string(), "0( 2( 5 ) 3 4 )" );
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+
+    #[test] fn retain_recursive() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) ) /( tr(4)/tr(3) );
+        tree.root_mut().retain_recursive( |data| data % 2 == 0 );
+        assert_eq!( tree.to_string(), "0( 4 )" );
+        assert_eq!( tree.root().node_count(), 2 );
+    }
+
+    #[test] fn to_string_tree() {
+        use crate::{Tree, tr};
+
+        let tree: Tree<String> = ( tr(1)/tr(2) ).root().to_string_tree();
+        assert_eq!( tree.to_string(), "1( 2 )" );
+        assert_eq!( tree.root().data(), "1" );
+    }
+
+    #[test] fn to_sexp() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+        assert_eq!( tree.root().to_sexp(), "(0 (1 2 3) 4)" );
+        assert_eq!( tr(5).root().to_sexp(), "5" );
+    }
+
+    #[test] fn clone_filtered() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /( tr(9)/tr(3) );
+        let cloned = tree.root().clone_filtered( |data| *data < 5 ).unwrap();
+        assert_eq!( cloned.to_string(), "0( 1( 2 ) )" );
+        assert_eq!( tree.root().clone_filtered( |data| *data > 0 ), None );
+    }
+
+    #[test] fn map_in_place() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) );
+        tree.root_mut().map_in_place( |data| *data += 1 );
+        assert_eq!( tree.to_string(), "1( 2( 3 ) )" );
+    }
+
+    #[test] fn reduce() {
+        use crate::tr;
+
+        let tree = tr(1) /( tr(2)/tr(3) ) /tr(4);
+        assert_eq!( tree.root().reduce( |data, children| data + children.iter().sum::<i32>() ), 10 );
+    }
+
+    #[test] fn iter_mut_with_depth() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(0)/tr(0) ) /tr(0);
+        tree.root_mut().iter_mut_with_depth( |depth, data| *data = depth );
+        assert_eq!( tree.to_string(), "0( 1( 2 ) 1 )" );
+    }
+
+    #[test] fn data_slices_mut() {
+        use crate::Tree;
+
+        let mut tree = Tree::<i32>::from_tuple(( 0, (1,2), (3,4) ));
+        tree.root_mut().data_slices_mut().into_iter().for_each( |data| *data *= 2 );
+        assert_eq!( tree.to_string(), "0( 2( 4 ) 6( 8 ) )" );
+    }
+
+    #[test] fn hash_structure_only() {
+        use crate::{Tree, tr};
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::Hasher;
+
+        let shape_hash = |tree: &Tree<i32>| {
+            let mut hasher = DefaultHasher::new();
+            tree.root().hash_structure_only( &mut hasher );
+            hasher.finish()
+        };
+
+        let a = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let b = tr(4) /( tr(5)/tr(6) ) /tr(7);
+        let c = tr(0) /tr(1) /( tr(2)/tr(3) );
+        assert_eq!( shape_hash( &a ), shape_hash( &b ));
+        assert_ne!( shape_hash( &a ), shape_hash( &c ));
+    }
+
+    #[test] fn subtree_hash() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /( tr(1)/tr(2) ) /( tr(1)/tr(2)/tr(3) );
+        let mut iter = tree.iter();
+        let a = iter.next().unwrap().subtree_hash();
+        let b = iter.next().unwrap().subtree_hash();
+        let c = iter.next().unwrap().subtree_hash();
+        assert_eq!( a, b );
+        assert_ne!( a, c );
+        assert_ne!(( tr(0)/tr(1)/tr(2) ).root().subtree_hash(), ( tr(0)/( tr(1)/tr(2) )).root().subtree_hash() );
+    }
+
+    #[test] fn longest_path_len() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2)/tr(3) );
+        assert_eq!( tree.root().longest_path_len(), 2 );
+        assert_eq!( tr(0).root().longest_path_len(), 0 );
+    }
+
+    #[test] fn balance_factor() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1) /( tr(2)/tr(3) )) /tr(4);
+        assert_eq!( tree.root().balance_factor(), 2 );
+        assert_eq!( tree.front().unwrap().balance_factor(), 0 );
+        assert_eq!( tr(5).root().balance_factor(), 0 );
+    }
+
+    #[test] fn levels() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let levels = tree.root().levels().into_iter()
+            .map( |level| level.into_iter().map( |node| *node.data() ).collect::<Vec<_>>() )
+            .collect::<Vec<_>>();
+        assert_eq!( levels, vec![ vec![0], vec![1,3], vec![2] ]);
+    }
+
+    #[test] fn min_by_key() {
+        use crate::tr;
+
+        let tree = tr(1) /( tr(5)/tr(2) ) /tr(3);
+        assert_eq!( tree.root().min_by_key( |data| *data ).unwrap().data(), &1 );
+        assert_eq!( tree.root().min_by_key( |data| ( *data - 3_i32 ).abs() ).unwrap().data(), &3 );
+    }
+
+    #[test] fn max_by_key() {
+        use crate::tr;
+
+        let tree = tr(1) /( tr(5)/tr(2) ) /tr(3);
+        let node = tree.root().max_by_key( |data| *data ).unwrap();
+        assert_eq!( node.data(), &5 );
+        assert_eq!( node.to_string(), "5( 2 )" );
+    }
+
+    #[test] fn to_json_string() {
+        use crate::tr;
+
+        let tree = tr(0) /tr(1);
+        assert_eq!( tree.root().to_json_string(), r#"{"data":0,"children":[{"data":1,"children":[]}]}"# );
+    }
+
+    #[test] fn display_with() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        assert_eq!( tree.root().display_with( "[", "]", "," ), "0[1[2],3]" );
+        assert_eq!( ( tr(0)/tr(1)/tr(2) ).root().display_with( "[", "]", "," ), "0[1,2]" );
+    }
+
+    #[test] fn prune_leaves_where() {
+        use crate::tr;
+
+        let mut tree = tr(1) /( tr(0)/tr(0) ) /( tr(0)/tr(2) ) /tr(0);
+        tree.root_mut().prune_leaves_where( |data| *data == 0 );
+        assert_eq!( tree.to_string(), "1( 0( 2 ) )" );
+        assert_eq!( tree.root().node_count(), 3 );
+    }
+
+    #[test] fn extract_matching() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(3) ) /( tr(2)/tr(5)/tr(6) ) /tr(7);
+        let forest = tree.root_mut().extract_matching( |data| data % 2 == 1 );
+        assert_eq!( forest.to_string(), "( 1( 3 ) 5 7 )" );
+        assert_eq!( tree.to_string(), "0( 2( 6 ) )" );
+        assert_eq!( tree.root().node_count(), 3 );
+    }
+
+    #[test] fn any() {
+        use crate::tr;
+
+        let tree = tr(2) /( tr(4)/tr(6) );
+        assert!( tree.root().any( |x| *x > 5 ));
+        assert!( !tree.root().any( |x| x % 2 == 1 ));
+    }
+
+    #[test] fn all() {
+        use crate::tr;
+
+        let tree = tr(2) /( tr(4)/tr(6) );
+        assert!( tree.root().all( |x| x % 2 == 0 ));
+        assert!( !tree.root().all( |x| *x > 2 ));
+    }
+
+    #[test] fn iter_with_paths() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let (path, _) = tree.root().iter_with_paths().find( |(_,node)| *node.data() == 2 ).unwrap();
+        assert_eq!( path, vec![ 0, 0 ]);
+        assert_eq!( tree.root().iter_with_paths().map( |(path,_)| path.len() ).collect::<Vec<_>>(), vec![ 0, 1, 2, 1 ]);
+    }
+
+    #[test] fn is_full_kary() {
+        use crate::tr;
+
+        assert!( ( tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4) ).root().is_full_kary( 2 ));
+        assert!( !( tr(0) /( tr(1)/tr(2) ) /tr(3) ).root().is_full_kary( 2 ));
+        assert!( tr(0).root().is_full_kary( 3 ));
+    }
+
+    #[test] fn degree_histogram() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let histogram = tree.root().degree_histogram();
+        assert_eq!( histogram.into_iter().collect::<Vec<_>>(), vec![ (0,2), (1,1), (2,1) ]);
+    }
+
+    #[test] fn eq_with() {
+        use crate::tr;
+
+        let a = tr(1.0_f64) /tr(2.0) /tr(3.0);
+        let b = tr(1.0_f64) /tr(2.000001) /tr(2.999999);
+        assert!( a.root().eq_with( b.root(), |x, y| ( x - y ).abs() < 1e-3 ));
+        assert!( !a.root().eq_with( b.root(), |x, y| x == y ));
+        assert!( !a.root().eq_with( ( tr(1.0)/tr(2.0) ).root(), |x, y| ( x - y ).abs() < 1e-3 ));
+    }
+
+    #[test] fn common_prefix() {
+        use crate::tr;
+
+        let a = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let b = tr(0) /( tr(1)/tr(9) ) /tr(3);
+        assert_eq!( a.root().common_prefix( b.root() ).to_string(), "0( 1 3 )" );
+    }
+
+    #[test] fn data_range() {
+        use crate::tr;
+
+        let tree = tr(3) /( tr(1)/tr(5) ) /tr(2);
+        assert_eq!( tree.root().data_range(), Some(( &1, &5 )));
+        assert_eq!( tr(7).root().data_range(), Some(( &7, &7 )));
+    }
+
+    #[test] fn same_shape() {
+        use crate::tr;
+
+        let tree = tr(1) /tr(2) /tr(3);
+        assert!( tree.root().same_shape( ( tr('a')/tr('b')/tr('c') ).root() ));
+        assert!( !tree.root().same_shape( ( tr(1)/tr(2) ).root() ));
+    }
+
+    #[test] fn count_matching_subtrees() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /( tr(3) /( tr(1)/tr(2) )) /( tr(1)/tr(2)/tr(4) );
+        assert_eq!( tree.root().count_matching_subtrees( ( tr(1)/tr(2) ).root() ), 2 );
+        assert_eq!( tree.root().count_matching_subtrees( tr(2).root() ), 3 );
+    }
+
+    #[test] fn edges() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) );
+        assert_eq!( tree.root().edges().collect::<Vec<_>>(), vec![ (&0,&1), (&1,&2) ]);
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        assert_eq!( tree.root().edges().collect::<Vec<_>>(), vec![ (&0,&1), (&0,&3), (&1,&2) ]);
+    }
+
+    #[test] fn collect_into() {
+        use std::collections::HashSet;
+        use crate::tr;
+
+        let tree = tr(1) /( tr(2)/tr(2) );
+        let mut set = HashSet::new();
+        tree.root().collect_into( &mut set );
+        assert_eq!( set.len(), 2 );
+
+        let mut vec = vec![ 0 ];
+        tree.root().collect_into( &mut vec );
+        assert_eq!( vec, vec![ 0, 1, 2, 2 ]);
+    }
+
+    #[test] fn satisfies_degree_bounds() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+        assert!( tree.root().satisfies_degree_bounds( 2, 3 ));
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        assert!( !tree.root().satisfies_degree_bounds( 2, 3 ));
+    }
 }
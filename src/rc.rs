@@ -208,6 +208,17 @@ impl<T> RcNode<T> {
         }
     }
 
+    pub(crate) fn try_node_borrow_mut( &self ) -> Option<RefMut<'_,Node<T>>> {
+        let borrowed = match self {
+            RcNode::Scattered( ScatteredRcNode( rc )) =>
+                rc.deref().try_borrow_mut().ok()?,
+            RcNode::Piled( PiledRcNode( node_vec, index )) =>
+                unsafe{ node_vec.as_ref().buf.get_unchecked( *index ).deref().try_borrow_mut().ok()? },
+        };
+        assert!( !borrowed.data.is_none() );
+        Some( borrowed )
+    }
+
     /// Returns `true` if this `Node` has no child node, otherwise `false`.
     ///
     /// # Examples
@@ -380,6 +391,46 @@ impl<T> RcNode<T> {
     /// ```
     pub fn append( &self, forest: Forest<T> ) { self.node_borrow_mut().append( forest )}
 
+    /// Adds the shared root node as the last child, linking its parent to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `child` is not a root node, if it is `self` or one of its ancestors,
+    /// or if either `self` or `child` is currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{RcNode, tr};
+    ///
+    /// let root = RcNode::from( tr(0) );
+    /// let shared = root.clone();
+    /// shared.append_child( RcNode::from( tr(1) ));
+    /// shared.append_child( RcNode::from( tr(2)/tr(3) ));
+    /// assert_eq!( root.to_string(), "0( 1 2( 3 ) )" );
+    /// let children = root.iter_rc().map( |child| *child.data() ).collect::<Vec<_>>();
+    /// assert_eq!( children, vec![ 1, 2 ]);
+    /// assert_eq!( root.back().unwrap().parent(), Some( root ));
+    /// ```
+    pub fn append_child( &self, child: RcNode<T> ) {
+        let child_node = child.try_node_borrow_mut()
+            .expect( "append_child: the child is already borrowed" )
+            .non_null();
+        assert!( child.is_root(), "append_child: the child must be a root node" );
+
+        let mut node = self.try_node_borrow_mut().expect( "append_child: the node is already borrowed" );
+        let mut ancestor = Some( &*node );
+        while let Some( ancestor_node ) = ancestor {
+            assert!( ancestor_node.non_null() != child_node, "append_child: a node cannot be appended under itself" );
+            ancestor = ancestor_node.parent();
+        }
+        // SAFETY: `child` is a root which is neither `self` nor one of its ancestors, and it is not borrowed,
+        // so its tree can be linked under `self` without forming a cycle or aliasing an active borrow.
+        // The strong reference held by `child` is handed over to the new child link,
+        // while other handles to the subtree stay valid because its nodes remain reference-counted.
+        node.push_back( unsafe{ child.into_tree() });
+    }
+
     /// Inserts sib tree before `self`.
     /// The newly inserted node will not be iterated over by the currently running iterator.
     ///
@@ -587,6 +638,28 @@ mod tests {
         assert_eq!( *rc_1.data(), 4 );
         assert_eq!( *rc_2.data(), 5 );
     }
+
+    #[test]
+    #[should_panic( expected = "append_child: the node is already borrowed" )]
+    fn append_child_while_node_borrowed() {
+        use super::super::{RcNode, tr};
+
+        let root = RcNode::from( tr(0) );
+        let _guard = root.node_borrow();
+        root.append_child( RcNode::from( tr(1) ));
+    }
+
+    #[test]
+    #[should_panic( expected = "append_child: the child is already borrowed" )]
+    fn append_child_while_child_borrowed() {
+        use super::super::{RcNode, tr};
+
+        let root = RcNode::from( tr(0) );
+        let child = RcNode::from( tr(1) );
+        let handle = child.clone();
+        let _guard = handle.node_borrow_mut();
+        root.append_child( child );
+    }
 }
 
 #[cfg( miri )]
@@ -706,6 +779,19 @@ mod miri_tests {
         assert_eq!( root.to_string(), "0( 1 2 3 4 )" );
     }
 
+    #[test] fn append_child() {
+        use crate::{RcNode, tr};
+
+        let root = RcNode::from( tr(0) );
+        let shared = root.clone();
+        shared.append_child( RcNode::from( tr(1) ));
+        shared.append_child( RcNode::from( tr(2)/tr(3) ));
+        assert_eq!( root.to_string(), "0( 1 2( 3 ) )" );
+        let children = root.iter_rc().map( |child| *child.data() ).collect::<Vec<_>>();
+        assert_eq!( children, vec![ 1, 2 ]);
+        assert_eq!( root.back().unwrap().parent(), Some( root ));
+    }
+
     #[test] fn insert_prev_sib() {
         use crate::{RcNode, tr};
 
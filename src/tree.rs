@@ -12,7 +12,7 @@
 //!
 //! 5. Can be converted to `RcNode` which has shared ownership.
 
-use crate::TupleTree;
+use crate::{Size, TreeError, TupleTree};
 
 use crate::rust::*;
 
@@ -60,6 +60,75 @@ impl<T> Tree<T> {
         value
     }
 
+    /// Constructs tree from the root data and a list of `(parent, data)` edges.
+    ///
+    /// Nodes are numbered in order of definition: the root is `0` and the node
+    /// created by `edges[i]` is `i+1`. Each edge must refer to a parent defined before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, TreeError};
+    ///
+    /// let tree = Tree::from_edges( 0, &[ (0,1), (1,2), (0,3), (1,4) ]).unwrap();
+    /// assert_eq!( tree.to_string(), "0( 1( 2 4 ) 3 )" );
+    ///
+    /// assert_eq!( Tree::from_edges( 0, &[ (2,1), (0,2) ]), Err( TreeError::ForwardReference{ edge: 0, parent: 2 }));
+    /// assert_eq!( Tree::from_edges( 0, &[ (0,1), (5,2) ]), Err( TreeError::InvalidParent{ edge: 1, parent: 5 }));
+    /// ```
+    pub fn from_edges( root: T, edges: &[(usize,T)] ) -> Result<Tree<T>, TreeError>
+        where T: Clone
+    {
+        let tree = Tree::new( root );
+        let mut nodes = Vec::with_capacity( edges.len() + 1 );
+        nodes.push( tree.root );
+
+        for (edge, &(parent, ref data)) in edges.iter().enumerate() {
+            if parent >= nodes.len() {
+                return Err( if parent <= edges.len() {
+                    TreeError::ForwardReference{ edge, parent }
+                } else {
+                    TreeError::InvalidParent{ edge, parent }
+                });
+            }
+            let child = Tree::new( data.clone() );
+            let node = child.root;
+            unsafe{ nodes[ parent ].as_mut().push_back( child ); }
+            nodes.push( node );
+        }
+
+        Ok( tree )
+    }
+
+    /// Parses a tree from an S-expression, a leaf being its data and a branched node being `(data children...)`.
+    /// This is the inverse of `Node::to_sexp()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, TreeError, tr};
+    ///
+    /// let tree = Tree::<i32>::from_sexp( "(0 (1 2 3)\n 4)" ).unwrap();
+    /// assert_eq!( tree, tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4) );
+    /// assert_eq!( Tree::<i32>::from_sexp( &tree.root().to_sexp() ), Ok( tree ));
+    ///
+    /// assert_eq!( Tree::<i32>::from_sexp( "(0 1" ), Err( TreeError::UnexpectedEnd ));
+    /// assert_eq!( Tree::<i32>::from_sexp( "(0 x)" ), Err( TreeError::InvalidData{ offset: 3 }));
+    /// assert_eq!( Tree::<i32>::from_sexp( "(() 1)" ), Err( TreeError::UnexpectedChar{ offset: 1 }));
+    /// assert_eq!( Tree::<i32>::from_sexp( "(0 1) 2" ), Err( TreeError::TrailingBytes{ offset: 6 }));
+    /// ```
+    pub fn from_sexp( sexp: &str ) -> Result<Tree<T>, TreeError>
+        where T: FromStr
+    {
+        let mut offset = 0;
+        let tree = parse_sexp( sexp, &mut offset )?;
+        skip_whitespace( sexp, &mut offset );
+        if offset != sexp.len() {
+            return Err( TreeError::TrailingBytes{ offset });
+        }
+        Ok( tree )
+    }
+
     pub(crate) fn from_node( mut root: NonNull<Node<T>> ) -> Tree<T> {
         unsafe{ root.as_mut().up = None; }
         Tree{ root, mark: PhantomData }
@@ -73,6 +142,103 @@ impl<T> Tree<T> {
 
     pub(crate) fn root_mut_( &mut self ) -> &mut Node<T> { unsafe{ &mut *self.root.as_ptr() }}
 
+    /// Reference of the root node's data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(5);
+    /// assert_eq!( tree.root_data(), &5 );
+    /// *tree.root_data_mut() += 1;
+    /// assert_eq!( tree.root_data(), &6 );
+    /// ```
+    pub fn root_data( &self ) -> &T { self.root().data() }
+
+    /// Mutable reference of the root node's data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(5);
+    /// *tree.root_data_mut() = 7;
+    /// assert_eq!( tree.to_string(), "7" );
+    /// ```
+    pub fn root_data_mut( &mut self ) -> &mut T { self.root_mut_().data_mut() }
+
+    /// Takes the root's data out, leaving the default value in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(5) /tr(1);
+    /// assert_eq!( tree.take_root_data(), 5 );
+    /// assert_eq!( tree.to_string(), "0( 1 )" );
+    /// ```
+    pub fn take_root_data( &mut self ) -> T where T: Default { mem::take( self.root_data_mut() )}
+
+    /// Clones the tree structure with each data converted by `f`, the canonical structure-preserving conversion.
+    /// The new tree is stored contiguously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{Tree, tr};
+    ///
+    /// let tree = tr("secret") /tr("x");
+    /// let redacted: Tree<&str> = tree.clone_with( |_| "*" );
+    /// assert_eq!( redacted.to_string(), "*( * )" );
+    /// assert_eq!( tree.to_string(), "secret( x )" );
+    /// ```
+    pub fn clone_with<U,F:FnMut(&T)->U>( &self, f: F ) -> Tree<U> {
+        Tree::from( self.root().bfs().map( f ))
+    }
+
+    /// Returns all nodes in pre-order, each paired with its pre-order index starting at 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let numbering = tree.preorder_numbering();
+    /// assert_eq!( numbering.iter().map( |(index,_)| *index ).collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+    /// assert_eq!( numbering.iter().map( |(_,node)| *node.data() ).collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+    /// ```
+    pub fn preorder_numbering( &self ) -> Vec<(usize, &Node<T>)> {
+        self.root().subtree_preorder().enumerate().collect()
+    }
+
+    /// Swaps the data of the two nodes located by the child index paths from the root.
+    /// Swapping a node with itself does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either path does not locate a node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// tree.swap_data( &[], &[0,0] );
+    /// assert_eq!( tree.to_string(), "2( 1( 0 ) 3 )" );
+    /// ```
+    pub fn swap_data( &mut self, path_a: &[usize], path_b: &[usize] ) {
+        let a = self.root().at_path( path_a ).expect( "swap_data: invalid path_a" ).non_null();
+        let b = self.root().at_path( path_b ).expect( "swap_data: invalid path_b" ).non_null();
+        if a != b {
+            unsafe{ mem::swap( (*a.as_ptr()).data_mut(), (*b.as_ptr()).data_mut() ); }
+        }
+    }
+
     /// Provides a forward iterator over child `Node`s with mutable references.
     ///
     /// # Examples
@@ -182,6 +348,152 @@ impl<T> Tree<T> {
         forest
     }
 
+    /// Drops the root data and returns the children as a forest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let forest = tree.into_children();
+    /// assert_eq!( forest.to_string(), "( 1( 2 ) 3 )" );
+    /// ```
+    pub fn into_children( mut self ) -> Forest<T> { self.abandon() }
+
+    /// Rebuilds the tree into one fresh contiguous allocation.
+    ///
+    /// Nodes built from tuple notations or BFS iterators share a buffer which is
+    /// not released until all of its nodes are dropped, and nodes added one by one
+    /// are scattered among memory. Compacting moves every node into a new buffer
+    /// of exactly `node_count()` nodes, releasing the old storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6), (7,8,9) ));
+    /// tree.pop_back();
+    /// tree.front_mut().unwrap().pop_front();
+    /// tree.push_back( Tree::new(10) );
+    /// tree.compact();
+    /// assert_eq!( tree.to_string(), "0( 1( 3 ) 4( 5 6 ) 10 )" );
+    /// ```
+    pub fn compact( &mut self ) {
+        // A dataless root stands in while rebuilding, so that the nodes are owned by `tree` only,
+        // and an unwind in the rebuild drops them exactly once.
+        let placeholder = Tree::from_node( heap::make_node( Data::ScatteredNone{ owner: NonNull::dangling() }));
+        let tree = mem::replace( self, placeholder );
+        *self = Tree::from( tree.into_bfs() );
+    }
+
+    /// Moves the subtree at `node_path` to be the last child of the node at `new_parent_path`.
+    /// Both paths are child indices from the root, located before the move.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either path does not exist, if `node_path` is empty,
+    /// or if the new parent is the moved node itself or one of its descendants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// tree.reparent( &[0,1], &[1] );
+    /// assert_eq!( tree.to_string(), "0( 1( 2 ) 4( 3 ) )" );
+    /// assert_eq!( tree.root().node_count(), 5 );
+    /// assert_eq!( tree.front().unwrap().node_count(), 2 );
+    /// ```
+    pub fn reparent( &mut self, node_path: &[usize], new_parent_path: &[usize] ) {
+        assert!( !node_path.is_empty(), "reparent: the root node cannot be moved" );
+        assert!( !new_parent_path.starts_with( node_path ), "reparent: a node cannot be moved under itself" );
+
+        let mut new_parent = self.root().at_path( new_parent_path )
+            .expect( "reparent: new parent path does not exist" )
+            .non_null();
+        let node = self.root_mut_().at_path_mut( node_path )
+            .expect( "reparent: node path does not exist" );
+        let subtree = unsafe{ Pin::get_unchecked_mut( node )}.detach();
+        unsafe{ new_parent.as_mut().push_back( subtree ); }
+    }
+
+    /// Moves the root's `nth` child to be the child at `position` of the node at `new_parent_path`.
+    /// The path is child indices from the root, located before the move,
+    /// while `position` counts the new parent's children after the move.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child or the new parent does not exist, if `position` is out of range,
+    /// or if the new parent is the moved node itself or one of its descendants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2) ) /( tr(3)/tr(4)/tr(5) );
+    /// tree.move_child_to( 0, &[1], 1 );
+    /// assert_eq!( tree.to_string(), "0( 3( 4 1( 2 ) 5 ) )" );
+    /// assert_eq!( tree.front().unwrap().node_count(), 5 );
+    /// tree.move_child_to( 0, &[], 0 );
+    /// assert_eq!( tree.to_string(), "0( 3( 4 1( 2 ) 5 ) )" );
+    /// ```
+    pub fn move_child_to( &mut self, nth: usize, new_parent_path: &[usize], position: usize ) {
+        assert!( nth < self.root().degree(), "move_child_to: child index out of bounds" );
+        assert!( new_parent_path.first() != Some( &nth ), "move_child_to: a node cannot be moved under itself" );
+
+        let mut new_parent = self.root().at_path( new_parent_path )
+            .expect( "move_child_to: new parent path does not exist" )
+            .non_null();
+        // The root has at least the moved child, so its degree after the detach does not underflow.
+        let degree = unsafe{ new_parent.as_ref().degree() } - if new_parent_path.is_empty() { 1 } else { 0 };
+        assert!( position <= degree, "move_child_to: position out of bounds" );
+        let child = self.root_mut_().iter_mut().nth( nth ).unwrap();
+        let subtree = unsafe{ Pin::get_unchecked_mut( child )}.detach();
+        unsafe{ new_parent.as_mut().insert_child( position, subtree ); }
+    }
+
+    /// Checks the internal links and size information of the whole tree,
+    /// returning a description of the first inconsistency found.
+    ///
+    /// Nodes are located by their child index paths from the root in the error message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::Tree;
+    ///
+    /// let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6) ));
+    /// tree.push_back( Tree::new(7) );
+    /// assert_eq!( tree.validate(), Ok(()) );
+    /// ```
+    pub fn validate( &self ) -> Result<(), String> {
+        if self.root().up.is_some() {
+            return Err( String::from( "root node has a parent" ));
+        }
+        validate_node( self.root(), &mut Vec::new() ).map( |_| () )
+    }
+
+    /// Recomputes the size information of every node in post-order, from the child links actually present,
+    /// overwriting the stored values. Complements `validate()` as a recovery tool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let mut tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// tree.recompute_sizes();
+    /// assert_eq!( tree.node_count(), 4 );
+    /// assert_eq!( tree.validate(), Ok(()) );
+    /// ```
+    pub fn recompute_sizes( &mut self ) {
+        recompute_node_sizes( self.root_mut_() );
+    }
+
     /// Removes and returns the first child.
     ///
     /// # Examples
@@ -252,6 +564,115 @@ impl_debug_display_for_collection!( Tree, root() );
 impl_order_relations_for_collection!( Tree, root() );
 impl_hash_for_collection!( Tree, root() );
 
+fn skip_whitespace( sexp: &str, offset: &mut usize ) {
+    while let Some( byte ) = sexp.as_bytes().get( *offset ) {
+        if !byte.is_ascii_whitespace() {
+            break;
+        }
+        *offset += 1;
+    }
+}
+
+fn parse_sexp<T:FromStr>( sexp: &str, offset: &mut usize ) -> Result<Tree<T>, TreeError> {
+    skip_whitespace( sexp, offset );
+    match sexp.as_bytes().get( *offset ) {
+        None => Err( TreeError::UnexpectedEnd ),
+        Some( b')' ) => Err( TreeError::UnexpectedChar{ offset: *offset }),
+        Some( b'(' ) => {
+            *offset += 1;
+            skip_whitespace( sexp, offset );
+            match sexp.as_bytes().get( *offset ) {
+                None => return Err( TreeError::UnexpectedEnd ),
+                Some( b'(' ) | Some( b')' ) => return Err( TreeError::UnexpectedChar{ offset: *offset }),
+                _ => (),
+            }
+            let mut tree = Tree::new( parse_sexp_atom( sexp, offset )? );
+            loop {
+                skip_whitespace( sexp, offset );
+                match sexp.as_bytes().get( *offset ) {
+                    None => return Err( TreeError::UnexpectedEnd ),
+                    Some( b')' ) => {
+                        *offset += 1;
+                        return Ok( tree );
+                    },
+                    _ => tree.push_back( parse_sexp( sexp, offset )? ),
+                }
+            }
+        },
+        _ => Ok( Tree::new( parse_sexp_atom( sexp, offset )? )),
+    }
+}
+
+fn parse_sexp_atom<T:FromStr>( sexp: &str, offset: &mut usize ) -> Result<T, TreeError> {
+    let start = *offset;
+    while let Some( &byte ) = sexp.as_bytes().get( *offset ) {
+        if byte == b'(' || byte == b')' || byte.is_ascii_whitespace() {
+            break;
+        }
+        *offset += 1;
+    }
+    sexp[ start..*offset ].parse().map_err( |_| TreeError::InvalidData{ offset: start })
+}
+
+// Returns the node count of the subtree on success.
+fn validate_node<T>( node: &Node<T>, path: &mut Vec<usize> ) -> Result<usize, String> {
+    let this = node.non_null();
+    if node.head.is_none() != node.tail.is_none() {
+        return Err( format!( "node at {:?}: only one of head and tail is set", path ));
+    }
+    if let Some( head ) = node.head {
+        if unsafe{ head.as_ref().prev.is_some() } {
+            return Err( format!( "node at {:?}: first child has a previous sibling", path ));
+        }
+    }
+
+    let mut degree = 0;
+    let mut descendants = 0;
+    let mut prev = None;
+    let mut curr = node.head;
+    while let Some( child ) = curr {
+        let child = unsafe{ child.as_ref() };
+        path.push( degree );
+        if child.up != Some( this ) {
+            return Err( format!( "node at {:?}: parent link does not point to its container", path ));
+        }
+        if child.prev != prev {
+            return Err( format!( "node at {:?}: previous sibling link is inconsistent", path ));
+        }
+        descendants += validate_node( child, path )?;
+        path.pop();
+        degree += 1;
+        prev = Some( child.non_null() );
+        curr = child.next;
+    }
+
+    if node.tail != prev {
+        return Err( format!( "node at {:?}: tail does not point to the last child", path ));
+    }
+    if node.size.degree != degree {
+        return Err( format!( "node at {:?}: degree is {}, but {} children found", path, node.size.degree, degree ));
+    }
+    if node.size.descendants != descendants {
+        return Err( format!( "node at {:?}: descendants is {}, but {} descendants found", path, node.size.descendants, descendants ));
+    }
+    Ok( descendants + 1 )
+}
+
+// Returns the node count of the subtree.
+fn recompute_node_sizes<T>( node: &mut Node<T> ) -> usize {
+    let mut degree = 0;
+    let mut descendants = 0;
+    let mut curr = node.head;
+    while let Some( mut child ) = curr {
+        let child = unsafe{ child.as_mut() };
+        descendants += recompute_node_sizes( child );
+        degree += 1;
+        curr = child.next;
+    }
+    node.size = Size{ degree, descendants };
+    descendants + 1
+}
+
 #[cfg( test )]
 mod tests {
     use super::*;
@@ -261,6 +682,59 @@ mod tests {
         let piled = Tree::<i32>::from_tuple( tuple );
         assert_eq!( piled.to_string(), "0( 1( 2 3 ) 4( 5 6 ) )" );
     }
+
+    #[test] fn compact_reclaims_dropped_nodes() {
+        let buf_len = |tree: &Tree<i32>| match &tree.root().data {
+            Data::Piled{ owner, .. } => unsafe{ owner.as_ref().buf.len() },
+            _ => unreachable!(),
+        };
+
+        let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6), (7,8,9) ));
+        assert_eq!( buf_len( &tree ), 10 );
+        tree.pop_back();
+        tree.pop_back();
+        tree.front_mut().unwrap().pop_front();
+        assert_eq!( buf_len( &tree ), 10 );
+
+        tree.compact();
+        assert_eq!( tree.to_string(), "0( 1( 3 ) )" );
+        assert_eq!( tree.root().node_count(), 3 );
+        assert_eq!( buf_len( &tree ), 3 );
+    }
+
+    #[test] fn validate_detects_corruption() {
+        let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6) ));
+        tree.push_back( Tree::new(7) );
+        assert_eq!( tree.validate(), Ok(()) );
+
+        tree.root_mut_().size.descendants += 1;
+        assert_eq!( tree.validate(), Err( String::from( "node at []: descendants is 8, but 7 descendants found" )));
+        tree.root_mut_().size.descendants -= 1;
+
+        let tail = tree.root_mut_().tail.take();
+        assert_eq!( tree.validate(), Err( String::from( "node at []: only one of head and tail is set" )));
+        tree.root_mut_().tail = tail;
+
+        let mut first = tree.root_mut_().head.unwrap();
+        let up = unsafe{ first.as_mut().up.take() };
+        assert_eq!( tree.validate(), Err( String::from( "node at [0]: parent link does not point to its container" )));
+        unsafe{ first.as_mut().up = up; }
+
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+
+    #[test] fn recompute_sizes() {
+        let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6) ));
+        tree.push_back( Tree::new(7) );
+        tree.root_mut_().size.descendants += 5;
+        unsafe{ tree.root_mut_().head.unwrap().as_mut().size.degree = 0; }
+        assert!( tree.validate().is_err() );
+
+        tree.recompute_sizes();
+        assert_eq!( tree.validate(), Ok(()) );
+        assert_eq!( tree.node_count(), 8 );
+        assert_eq!( tree.front().unwrap().degree(), 2 );
+    }
 }
 
 #[cfg( miri )]
@@ -319,6 +793,14 @@ mod miri_tests {
         assert_eq!( tree.to_string(), "0( 1 2 3 4 )" );
     }
 
+    #[test] fn into_children() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let forest = tree.into_children();
+        assert_eq!( forest.to_string(), "( 1( 2 ) 3 )" );
+    }
+
     #[test] fn abandon() {
         use crate::Tree;
 
@@ -330,6 +812,157 @@ mod miri_tests {
         assert_eq!( tree, Tree::new(0) );
     }
 
+    #[test] fn root_data() {
+        use crate::tr;
+
+        let mut tree = tr(5);
+        assert_eq!( tree.root_data(), &5 );
+        *tree.root_data_mut() += 1;
+        assert_eq!( tree.root_data(), &6 );
+    }
+
+    #[test] fn root_data_mut() {
+        use crate::tr;
+
+        let mut tree = tr(5);
+        *tree.root_data_mut() = 7;
+        assert_eq!( tree.to_string(), "7" );
+    }
+
+    #[test] fn clone_with() {
+        use crate::{Tree, tr};
+
+        let tree = tr("secret") /tr("x");
+        let redacted: Tree<&str> = tree.clone_with( |_| "*" );
+        assert_eq!( redacted.to_string(), "*( * )" );
+        assert_eq!( tree.to_string(), "secret( x )" );
+    }
+
+    #[test] fn take_root_data() {
+        use crate::tr;
+
+        let mut tree = tr(5) /tr(1);
+        assert_eq!( tree.take_root_data(), 5 );
+        assert_eq!( tree.to_string(), "0( 1 )" );
+    }
+
+    #[test] fn preorder_numbering() {
+        use crate::tr;
+
+        let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        let numbering = tree.preorder_numbering();
+        assert_eq!( numbering.iter().map( |(index,_)| *index ).collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+        assert_eq!( numbering.iter().map( |(_,node)| *node.data() ).collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+    }
+
+    #[test] fn swap_data() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        tree.swap_data( &[], &[0,0] );
+        assert_eq!( tree.to_string(), "2( 1( 0 ) 3 )" );
+    }
+
+    #[test] fn move_child_to() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) ) /( tr(3)/tr(4)/tr(5) );
+        tree.move_child_to( 0, &[1], 1 );
+        assert_eq!( tree.to_string(), "0( 3( 4 1( 2 ) 5 ) )" );
+        assert_eq!( tree.front().unwrap().node_count(), 5 );
+        tree.move_child_to( 0, &[], 0 );
+        assert_eq!( tree.to_string(), "0( 3( 4 1( 2 ) 5 ) )" );
+    }
+
+    #[test] fn from_edges() {
+        use crate::{Tree, TreeError};
+
+        let tree = Tree::from_edges( 0, &[ (0,1), (1,2), (0,3), (1,4) ]).unwrap();
+        assert_eq!( tree.to_string(), "0( 1( 2 4 ) 3 )" );
+
+        assert_eq!( Tree::from_edges( 0, &[ (2,1), (0,2) ]), Err( TreeError::ForwardReference{ edge: 0, parent: 2 }));
+        assert_eq!( Tree::from_edges( 0, &[ (0,1), (5,2) ]), Err( TreeError::InvalidParent{ edge: 1, parent: 5 }));
+    }
+
+    #[test] fn from_sexp() {
+        use crate::{Tree, TreeError, tr};
+
+        let tree = Tree::<i32>::from_sexp( "(0 (1 2 3)\n 4)" ).unwrap();
+        assert_eq!( tree, tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4) );
+        assert_eq!( Tree::<i32>::from_sexp( &tree.root().to_sexp() ), Ok( tree ));
+
+        assert_eq!( Tree::<i32>::from_sexp( "(0 1" ), Err( TreeError::UnexpectedEnd ));
+        assert_eq!( Tree::<i32>::from_sexp( "(0 x)" ), Err( TreeError::InvalidData{ offset: 3 }));
+        assert_eq!( Tree::<i32>::from_sexp( "(() 1)" ), Err( TreeError::UnexpectedChar{ offset: 1 }));
+        assert_eq!( Tree::<i32>::from_sexp( "(0 1) 2" ), Err( TreeError::TrailingBytes{ offset: 6 }));
+    }
+
+    #[test] fn compact() {
+        use crate::Tree;
+
+        let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6), (7,8,9) ));
+        tree.pop_back();
+        tree.front_mut().unwrap().pop_front();
+        tree.push_back( Tree::new(10) );
+        tree.compact();
+        assert_eq!( tree.to_string(), "0( 1( 3 ) 4( 5 6 ) 10 )" );
+    }
+
+    #[test] fn reparent() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+        tree.reparent( &[0,1], &[1] );
+        assert_eq!( tree.to_string(), "0( 1( 2 ) 4( 3 ) )" );
+        assert_eq!( tree.root().node_count(), 5 );
+        assert_eq!( tree.front().unwrap().node_count(), 2 );
+        assert_eq!( tree.validate(), Ok(()) );
+
+        tree.reparent( &[1], &[0,0] );
+        assert_eq!( tree.to_string(), "0( 1( 2( 4( 3 ) ) ) )" );
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+
+    #[test] #[should_panic] fn reparent_under_itself() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) );
+        tree.reparent( &[0], &[0,0] );
+    }
+
+    #[test] #[should_panic] fn move_child_to_under_itself() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) );
+        tree.move_child_to( 0, &[0,0], 0 );
+    }
+
+    #[test] #[should_panic( expected = "move_child_to: child index out of bounds" )]
+    fn move_child_to_from_childless_root() {
+        use crate::tr;
+
+        let mut tree = tr(0) /tr(1);
+        tree.pop_back();
+        tree.move_child_to( 0, &[], 0 );
+    }
+
+    #[test] fn recompute_sizes() {
+        use crate::tr;
+
+        let mut tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+        tree.recompute_sizes();
+        assert_eq!( tree.node_count(), 4 );
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+
+    #[test] fn validate() {
+        use crate::Tree;
+
+        let mut tree = Tree::<i32>::from_tuple(( 0, (1,2,3), (4,5,6) ));
+        tree.push_back( Tree::new(7) );
+        assert_eq!( tree.validate(), Ok(()) );
+    }
+
     #[test] fn pop_front() {
         use crate::Tree;
 
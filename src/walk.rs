@@ -24,6 +24,7 @@ impl<'a, T:'a> Visit<'a,T> {
 }
 
 // Mapping to Option<Visit>
+#[derive( Copy, Clone )]
 enum VisitType{ None, Begin, End, Leaf }
 
 /// Cursor on `Node` and its siblings.
@@ -32,6 +33,10 @@ struct Nodes<T> {
     sentinel : Option<NonNull<Node<T>>>,
 }
 
+impl<T> Clone for Nodes<T> {
+    fn clone( &self ) -> Self { Nodes{ node: self.node, sentinel: self.sentinel }}
+}
+
 impl<T> Nodes<T> {
     /// Only the given node will be visited.
     fn this( node: Option<NonNull<Node<T>>> ) -> Self { Nodes{ node, sentinel: node.map( |node| unsafe{ node.as_ref().next }).unwrap_or( None ) }}
@@ -41,6 +46,7 @@ impl<T> Nodes<T> {
 }
 
 // Control of the `Walk`'s stack.
+#[derive( Copy, Clone )]
 enum Direction {
     Up,    // Current node and all its siblings and all their descendents have been visited, so go back to their parent.
     Down,  // Try to visit the first child of the current node.
@@ -178,6 +184,21 @@ impl<T> Walk<T> {
         self.get()
     }
 
+    /// Returns the node which `next()` would visit, leaving the cursor unchanged.
+    fn peek_next( &mut self ) -> Option<Visit<'_,T>> {
+        let (path, direction, visit_type) = (self.path.clone(), self.direction, self.visit_type);
+        self.forward();
+        let visit = self.get().map( |visit| unsafe { match visit {
+            Visit::Begin( node ) => Visit::Begin( &*node.non_null().as_ptr() ),
+            Visit::End  ( node ) => Visit::End  ( &*node.non_null().as_ptr() ),
+            Visit::Leaf ( node ) => Visit::Leaf ( &*node.non_null().as_ptr() ),
+        }});
+        self.path = path;
+        self.direction = direction;
+        self.visit_type = visit_type;
+        visit
+    }
+
     /// Sets the cursor to the current node's parent and returns it, or `None` if it has no parent.
     fn to_parent( &mut self ) -> Option<Visit<T>> {
         if self.path.last().is_some() {
@@ -247,6 +268,27 @@ impl<T> Walk<T> {
         new_nodes.map( |nodes| self.path.push( nodes ));
         self.to_sib( n )
     }
+
+    /// Sets the cursor to the current node's `n`-th child counting from the back and returns it,
+    /// or `None` without moving the cursor if there is no such child.
+    /// Notice that `n == 0` indicating the last child.
+    #[allow( clippy::wrong_self_convention )]
+    fn to_child_back( &mut self, n: usize ) -> Option<Visit<'_,T>> {
+        let node = self.path.last()?.node?;
+        unsafe {
+            if node.as_ref().degree() <= n {
+                return None;
+            }
+            let mut child = node.as_ref().tail.unwrap();
+            for _ in 0..n {
+                child = child.as_ref().prev.unwrap();
+            }
+            self.path.push( Nodes::sibs( Some( child )));
+            self.visit_type = if child.as_ref().has_no_child() { VisitType::Leaf } else { VisitType::Begin };
+        }
+        self.direction = Direction::Down;
+        self.get()
+    }
 }
 
 impl<T> Default for Walk<T> {
@@ -255,6 +297,52 @@ impl<T> Default for Walk<T> {
     }
 }
 
+// Post-order iterator on `Node`, emitting on `Visit::End` and `Visit::Leaf`.
+struct Postorder<'a, T> {
+    walk    : Walk<T>,
+    started : bool,
+    mark    : PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T:'a> Iterator for Postorder<'a,T> {
+    type Item = &'a Node<T>;
+
+    fn next( &mut self ) -> Option<Self::Item> {
+        loop {
+            if self.started {
+                self.walk.forward();
+            } else {
+                self.started = true;
+            }
+            match self.walk.get() {
+                Some( Visit::Begin( _    )) => continue,
+                Some( Visit::End  ( node )) |
+                Some( Visit::Leaf ( node )) => return Some( unsafe{ &*node.non_null().as_ptr() }),
+                None => return None,
+            }
+        }
+    }
+}
+
+impl<T> Node<T> {
+    /// Provides a forward iterator in a depth-first manner, which visits children before their parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::tr;
+    ///
+    /// let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+    /// let order = tree.root().postorder().map( |node| *node.data() ).collect::<Vec<_>>();
+    /// assert_eq!( order, vec![ 2, 3, 1, 4, 0 ]);
+    /// ```
+    pub fn postorder<'a, 's:'a>( &'s self ) -> impl Iterator<Item=&'a Node<T>> {
+        let mut walk = Walk::<T>::default();
+        walk.on_node( Some( self.non_null() ));
+        Postorder{ walk, started: false, mark: PhantomData }
+    }
+}
+
 /// Depth first search in tree.
 pub struct TreeWalk<T> {
     tree : Tree<T>,
@@ -328,6 +416,35 @@ impl<T> TreeWalk<T> {
     /// ```
     pub fn next( &mut self ) -> Option<Visit<T>> { self.walk.next() }
 
+    /// Returns the current node in the tree traversal, the same as `get()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{TreeWalk, tr, walk::Visit};
+    /// let tree = tr(0) / tr(1)/tr(2)/tr(3);
+    /// let walk = TreeWalk::from( tree );
+    /// assert_eq!( walk.peek(), walk.get() );
+    /// ```
+    pub fn peek( &self ) -> Option<Visit<'_,T>> { self.walk.get() }
+
+    /// Returns the node which `next()` would visit, without advancing the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{TreeWalk, tr, walk::Visit};
+    /// let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+    /// let mut walk = TreeWalk::from( tree );
+    /// assert_eq!( walk.peek_next(), Some( Visit::Begin( (tr(1)/tr(2)).root() )));
+    /// assert_eq!( walk.peek_next(), Some( Visit::Begin( (tr(1)/tr(2)).root() )));
+    /// assert_eq!( walk.get(), Some( Visit::Begin( ( tr(0) /( tr(1)/tr(2) ) /tr(3) ).root() )));
+    /// assert_eq!( walk.next(), Some( Visit::Begin( (tr(1)/tr(2)).root() )));
+    /// assert_eq!( walk.peek_next(), Some( Visit::Leaf( tr(2).root() )));
+    /// assert_eq!( walk.next(), Some( Visit::Leaf( tr(2).root() )));
+    /// ```
+    pub fn peek_next( &mut self ) -> Option<Visit<'_,T>> { self.walk.peek_next() }
+
     /// Set the cursor to the current node's parent and returns it, or `None` if it has no parent.
     ///
     /// # Examples
@@ -374,6 +491,25 @@ impl<T> TreeWalk<T> {
     /// ```
     pub fn to_child( &mut self, n: usize ) -> Option<Visit<T>> { self.walk.to_child(n) }
 
+    /// Sets the cursor to the current node's `n`-th child counting from the back and returns it,
+    /// or `None` without moving the cursor if there is no such child.
+    /// Notice that `n == 0` indicating the last child.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{TreeWalk, tr, walk::Visit};
+    /// let tree = tr(0) /tr(1) /tr(2) /tr(3);
+    /// let mut walk = TreeWalk::from( tree );
+    /// assert_eq!( walk.to_child_back( 0 ), Some( Visit::Leaf( tr(3).root() )));
+    /// assert_eq!( walk.next(), Some( Visit::End( ( tr(0)/tr(1)/tr(2)/tr(3) ).root() )));
+    /// walk.revisit();
+    /// assert_eq!( walk.to_child_back( 3 ), None );
+    /// assert_eq!( walk.to_child_back( 2 ), Some( Visit::Leaf( tr(1).root() )));
+    /// assert_eq!( walk.next(), Some( Visit::Leaf( tr(2).root() )));
+    /// ```
+    pub fn to_child_back( &mut self, n: usize ) -> Option<Visit<'_,T>> { self.walk.to_child_back(n) }
+
     /// Sets the cursor to the current node's next `n`-th sibling and returns it, or `None` if such sibling does not exist.
     /// Returns the current node if n == 0.
     ///
@@ -434,6 +570,33 @@ impl<T> TreeWalk<T> {
     /// }
     /// ```
     pub fn revisit( &mut self ) { self.walk.revisit(); }
+
+    /// Borrows the walked tree without ending the traversal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{TreeWalk, tr, walk::Visit};
+    /// let tree = tr(0) /tr(1) /tr(2);
+    /// let mut walk = TreeWalk::from( tree );
+    /// walk.forward();
+    /// assert_eq!( walk.tree().to_string(), "0( 1 2 )" );
+    /// assert_eq!( walk.get(), Some( Visit::Leaf( tr(1).root() )));
+    /// ```
+    pub fn tree( &self ) -> &Tree<T> { &self.tree }
+
+    /// Ends the traversal and returns the walked tree, regardless of the cursor position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{TreeWalk, tr};
+    /// let tree = tr(0) /tr(1) /tr(2);
+    /// let mut walk = TreeWalk::from( tree );
+    /// walk.forward();
+    /// assert_eq!( walk.into_tree(), tr(0) /tr(1) /tr(2) );
+    /// ```
+    pub fn into_tree( self ) -> Tree<T> { self.tree }
 }
 
 impl<T> From<Tree<T>> for TreeWalk<T> {
@@ -446,7 +609,7 @@ impl<T> From<Tree<T>> for TreeWalk<T> {
 
 impl<T> From<TreeWalk<T>> for Tree<T> {
     fn from( walk: TreeWalk<T> ) -> Self {
-        walk.tree
+        walk.into_tree()
     }
 }
 
@@ -522,6 +685,34 @@ impl<T> ForestWalk<T> {
     /// ```
     pub fn next( &mut self ) -> Option<Visit<T>> { self.walk.next() }
 
+    /// Returns the current node in the forest traversal, the same as `get()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{ForestWalk, tr, walk::Visit};
+    /// let forest = -tr(1)-tr(2)-tr(3);
+    /// let walk = ForestWalk::from( forest );
+    /// assert_eq!( walk.peek(), walk.get() );
+    /// ```
+    pub fn peek( &self ) -> Option<Visit<'_,T>> { self.walk.get() }
+
+    /// Returns the node which `next()` would visit, without advancing the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{ForestWalk, tr, walk::Visit};
+    /// let forest = -tr(1)-tr(2);
+    /// let mut walk = ForestWalk::from( forest );
+    /// assert_eq!( walk.peek_next(), Some( Visit::Leaf( tr(2).root() )));
+    /// assert_eq!( walk.get(), Some( Visit::Leaf( tr(1).root() )));
+    /// assert_eq!( walk.next(), Some( Visit::Leaf( tr(2).root() )));
+    /// assert_eq!( walk.peek_next(), None );
+    /// assert_eq!( walk.next(), None );
+    /// ```
+    pub fn peek_next( &mut self ) -> Option<Visit<'_,T>> { self.walk.peek_next() }
+
     /// Sets the cursor to the current node's parent and returns it, or `None` if it has no parent.
     ///
     /// # Examples
@@ -568,6 +759,21 @@ impl<T> ForestWalk<T> {
     /// ```
     pub fn to_child( &mut self, n: usize ) -> Option<Visit<T>> { self.walk.to_child(n) }
 
+    /// Sets the cursor to the current node's `n`-th child counting from the back and returns it,
+    /// or `None` without moving the cursor if there is no such child.
+    /// Notice that `n == 0` indicating the last child.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use trees::{ForestWalk, tr, walk::Visit};
+    /// let forest = - ( tr(1)/tr(2)/tr(3) ) - ( tr(4)/tr(5)/tr(6) );
+    /// let mut walk = ForestWalk::from( forest );
+    /// assert_eq!( walk.to_child_back( 0 ), Some( Visit::Leaf( tr(3).root() )));
+    /// assert_eq!( walk.next(), Some( Visit::End( (tr(1)/tr(2)/tr(3)).root() )));
+    /// ```
+    pub fn to_child_back( &mut self, n: usize ) -> Option<Visit<'_,T>> { self.walk.to_child_back(n) }
+
     /// Sets the cursor to the current node's next `n`-th sibling and returns it, or `None` if such sibling does not exist.
     /// Returns the current node if n == 0.
     ///
@@ -695,6 +901,27 @@ mod miri_tests {
             assert_eq!( walk.next(), None );
         }
 
+        #[test] fn peek() {
+            use crate::{TreeWalk, tr};
+
+            let tree = tr(0) / tr(1)/tr(2)/tr(3);
+            let walk = TreeWalk::from( tree );
+            assert_eq!( walk.peek(), walk.get() );
+        }
+
+        #[test] fn peek_next() {
+            use crate::{TreeWalk, tr, walk::Visit};
+
+            let tree = tr(0) /( tr(1)/tr(2) ) /tr(3);
+            let mut walk = TreeWalk::from( tree );
+            assert_eq!( walk.peek_next(), Some( Visit::Begin( (tr(1)/tr(2)).root() )));
+            assert_eq!( walk.peek_next(), Some( Visit::Begin( (tr(1)/tr(2)).root() )));
+            assert_eq!( walk.get(), Some( Visit::Begin( ( tr(0) /( tr(1)/tr(2) ) /tr(3) ).root() )));
+            assert_eq!( walk.next(), Some( Visit::Begin( (tr(1)/tr(2)).root() )));
+            assert_eq!( walk.peek_next(), Some( Visit::Leaf( tr(2).root() )));
+            assert_eq!( walk.next(), Some( Visit::Leaf( tr(2).root() )));
+        }
+
         #[test] fn to_parent() {
             use crate::{TreeWalk, tr, walk::Visit};
 
@@ -728,6 +955,38 @@ mod miri_tests {
             assert_eq!( walk.get(), Some( Visit::Begin( (tr(4)/tr(5)/tr(6)).root() )));
         }
 
+        #[test] fn tree() {
+            use crate::{TreeWalk, tr, walk::Visit};
+
+            let tree = tr(0) /tr(1) /tr(2);
+            let mut walk = TreeWalk::from( tree );
+            walk.forward();
+            assert_eq!( walk.tree().to_string(), "0( 1 2 )" );
+            assert_eq!( walk.get(), Some( Visit::Leaf( tr(1).root() )));
+        }
+
+        #[test] fn into_tree() {
+            use crate::{TreeWalk, tr};
+
+            let tree = tr(0) /tr(1) /tr(2);
+            let mut walk = TreeWalk::from( tree );
+            walk.forward();
+            assert_eq!( walk.into_tree(), tr(0) /tr(1) /tr(2) );
+        }
+
+        #[test] fn to_child_back() {
+            use crate::{TreeWalk, tr, walk::Visit};
+
+            let tree = tr(0) /tr(1) /tr(2) /tr(3);
+            let mut walk = TreeWalk::from( tree );
+            assert_eq!( walk.to_child_back( 0 ), Some( Visit::Leaf( tr(3).root() )));
+            assert_eq!( walk.next(), Some( Visit::End( ( tr(0)/tr(1)/tr(2)/tr(3) ).root() )));
+            walk.revisit();
+            assert_eq!( walk.to_child_back( 3 ), None );
+            assert_eq!( walk.to_child_back( 2 ), Some( Visit::Leaf( tr(1).root() )));
+            assert_eq!( walk.next(), Some( Visit::Leaf( tr(2).root() )));
+        }
+
         #[test] fn to_sib() {
             use crate::{TreeWalk, tr, walk::Visit};
 
@@ -827,6 +1086,26 @@ mod miri_tests {
             assert_eq!( walk.next(), None );
         }
 
+        #[test] fn peek() {
+            use crate::{ForestWalk, tr};
+
+            let forest = -tr(1)-tr(2)-tr(3);
+            let walk = ForestWalk::from( forest );
+            assert_eq!( walk.peek(), walk.get() );
+        }
+
+        #[test] fn peek_next() {
+            use crate::{ForestWalk, tr, walk::Visit};
+
+            let forest = -tr(1)-tr(2);
+            let mut walk = ForestWalk::from( forest );
+            assert_eq!( walk.peek_next(), Some( Visit::Leaf( tr(2).root() )));
+            assert_eq!( walk.get(), Some( Visit::Leaf( tr(1).root() )));
+            assert_eq!( walk.next(), Some( Visit::Leaf( tr(2).root() )));
+            assert_eq!( walk.peek_next(), None );
+            assert_eq!( walk.next(), None );
+        }
+
         #[test] fn to_parent() {
             use crate::{ForestWalk, tr, walk::Visit};
 
@@ -860,6 +1139,15 @@ mod miri_tests {
             assert_eq!( walk.get(), Some( Visit::Leaf ( tr(3).root() )));
         }
 
+        #[test] fn to_child_back() {
+            use crate::{ForestWalk, tr, walk::Visit};
+
+            let forest = - ( tr(1)/tr(2)/tr(3) ) - ( tr(4)/tr(5)/tr(6) );
+            let mut walk = ForestWalk::from( forest );
+            assert_eq!( walk.to_child_back( 0 ), Some( Visit::Leaf( tr(3).root() )));
+            assert_eq!( walk.next(), Some( Visit::End( (tr(1)/tr(2)/tr(3)).root() )));
+        }
+
         #[test] fn to_sib() {
             use crate::{ForestWalk, tr, walk::Visit};
 
@@ -903,4 +1191,14 @@ mod miri_tests {
             }
         }
     }
+
+    mod node {
+        #[test] fn postorder() {
+            use crate::tr;
+
+            let tree = tr(0) /( tr(1)/tr(2)/tr(3) ) /tr(4);
+            let order = tree.root().postorder().map( |node| *node.data() ).collect::<Vec<_>>();
+            assert_eq!( order, vec![ 2, 3, 1, 4, 0 ]);
+        }
+    }
 }